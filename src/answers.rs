@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Result};
+use toml::{Table, Value};
+
+use crate::input;
+use crate::solution::{Puzzle, Task};
+
+/// Accepted answers for one puzzle, read from `inputs/YYYY/dayN/answers.toml`.
+///
+/// Keys are task names as used on the command line:
+///
+/// ```toml
+/// part1 = 2378066
+/// part2 = "6,1"
+/// part1_optimized = 2378066
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: BTreeMap<String, String>,
+}
+
+impl Answers {
+    /// Loads the answers file for `puzzle`. A missing file means no answers
+    /// are known yet, which is not an error.
+    pub fn load(puzzle: Puzzle) -> Result<Self> {
+        let path = path(puzzle);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(err) => return Err(err).with_context(|| format!("Failed to read {}", path.display())),
+        };
+
+        Answers::parse(&contents).with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        Answers::from_table(contents.parse()?)
+    }
+
+    pub fn from_table(table: Table) -> Result<Self> {
+        let expected = table
+            .into_iter()
+            .map(|(key, value)| Ok((key.clone(), answer_from_value(&key, value)?)))
+            .collect::<Result<_>>()?;
+
+        Ok(Answers { expected })
+    }
+
+    pub fn get(&self, task: Task) -> Option<&str> {
+        self.expected.get(&task.to_string()).map(String::as_str)
+    }
+}
+
+fn answer_from_value(key: &str, value: Value) -> Result<String> {
+    match value {
+        Value::Integer(value) => Ok(value.to_string()),
+        Value::String(value) => Ok(value),
+        other => Err(anyhow!("Answer for {} must be an integer or a string, found {}", key, other.type_str())),
+    }
+}
+
+pub fn path(puzzle: Puzzle) -> PathBuf {
+    input::dir(puzzle).join("answers.toml")
+}
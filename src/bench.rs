@@ -0,0 +1,252 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+
+use crate::input::InputSource;
+use crate::registry::{DynSolution, Registry};
+use crate::runner::{catch, parse_input, resolve_params, select_tasks, with_silent_panics, Selection};
+use crate::solution::{Params, Puzzle, Task};
+use crate::table;
+
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions { warmup: 3, runs: 10 }
+    }
+}
+
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        let n = samples.len();
+        let percentile = |p: f64| samples[((p * n as f64).ceil() as usize).clamp(1, n) - 1];
+
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            min: samples[0],
+            median: percentile(0.5),
+            p95: percentile(0.95),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+pub enum Phase {
+    Parse,
+    Solve(Task),
+}
+
+pub struct Measurement {
+    pub puzzle: Puzzle,
+    pub phase: Phase,
+    pub stats: Result<Stats>,
+}
+
+fn sample<T>(options: &BenchOptions, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    for _ in 0..options.warmup {
+        black_box(catch(&mut f)?);
+    }
+
+    let mut samples = Vec::with_capacity(options.runs);
+    for _ in 0..options.runs {
+        let start = Instant::now();
+        let result = catch(&mut f);
+        samples.push(start.elapsed());
+        black_box(result?);
+    }
+
+    Ok(Stats::from_samples(samples))
+}
+
+/// Benchmarks parsing the day's input, then each of `tasks` against one
+/// parsed copy of it.
+pub fn bench_day(
+    solution: &dyn DynSolution,
+    source: &InputSource,
+    overrides: &Params,
+    tasks: &[Task],
+    options: &BenchOptions,
+) -> Vec<Measurement> {
+    let puzzle = solution.puzzle();
+
+    let loaded = source
+        .read(puzzle)
+        .map_err(anyhow::Error::from)
+        .and_then(|input| Ok((input, resolve_params(solution, source, overrides)?)));
+    let (input, params) = match loaded {
+        Ok(loaded) => loaded,
+        Err(err) => {
+            let message = format!("{:#}", err);
+            return tasks
+                .iter()
+                .map(|&task| Measurement { puzzle, phase: Phase::Solve(task), stats: Err(anyhow!("{}", message)) })
+                .collect();
+        }
+    };
+
+    let mut measurements = vec![Measurement {
+        puzzle,
+        phase: Phase::Parse,
+        stats: sample(options, || solution.parse(&input)),
+    }];
+
+    let parsed = parse_input(solution, source, &input);
+    measurements.extend(tasks.iter().map(|&task| {
+        let stats = match &parsed {
+            Ok(parsed) => sample(options, || solution.solve(parsed.as_ref(), task, &params)),
+            Err(err) => Err(anyhow!("{:#}", err)),
+        };
+        Measurement { puzzle, phase: Phase::Solve(task), stats }
+    }));
+
+    measurements
+}
+
+pub fn bench_all(
+    registry: &Registry,
+    selection: Selection,
+    task: Option<&str>,
+    source: &InputSource,
+    overrides: &Params,
+    options: &BenchOptions,
+) -> Vec<Measurement> {
+    with_silent_panics(|| {
+        registry
+            .iter()
+            .filter(|solution| selection.contains(solution.puzzle()))
+            .flat_map(|solution| bench_day(solution, source, overrides, &select_tasks(solution, task), options))
+            .collect()
+    })
+}
+
+/// How a variant's median solve time compares with the reference
+/// implementation of the same part, e.g. `2.31x faster than reference`.
+fn versus_reference(measurement: &Measurement, measurements: &[Measurement]) -> String {
+    let (Phase::Solve(task), Ok(stats)) = (&measurement.phase, &measurement.stats) else {
+        return String::new();
+    };
+    if task.variant.is_none() {
+        return String::new();
+    }
+
+    let reference = measurements.iter().find_map(|other| match (&other.phase, &other.stats) {
+        (Phase::Solve(other_task), Ok(other_stats))
+            if other.puzzle == measurement.puzzle && other_task.part == task.part && other_task.variant.is_none() =>
+        {
+            Some(other_stats)
+        }
+        _ => None,
+    });
+
+    match reference {
+        Some(reference) if !stats.median.is_zero() => {
+            let ratio = reference.median.as_secs_f64() / stats.median.as_secs_f64();
+            if ratio >= 1.0 {
+                format!("{:.2}x faster than reference", ratio)
+            } else {
+                format!("{:.2}x slower than reference", 1.0 / ratio)
+            }
+        }
+        _ => String::new(),
+    }
+}
+
+pub fn print_table(measurements: &[Measurement], options: &BenchOptions) {
+    let rows: Vec<Vec<String>> = measurements
+        .iter()
+        .map(|measurement| {
+            let (part, variant, phase) = match &measurement.phase {
+                Phase::Parse => (String::from("-"), String::from("-"), "parse"),
+                Phase::Solve(task) => (
+                    task.part.number().to_string(),
+                    task.variant.unwrap_or("-").to_string(),
+                    "solve",
+                ),
+            };
+
+            let mut row = vec![
+                measurement.puzzle.year.to_string(),
+                measurement.puzzle.day.to_string(),
+                part,
+                variant,
+                phase.to_string(),
+            ];
+            match &measurement.stats {
+                Ok(stats) => row.extend([
+                    format!("{:.2?}", stats.min),
+                    format!("{:.2?}", stats.median),
+                    format!("{:.2?}", stats.p95),
+                    format!("{:.2?}", stats.std_dev),
+                    versus_reference(measurement, measurements),
+                ]),
+                Err(err) => {
+                    row.extend(["-", "-", "-", "-"].map(String::from));
+                    row.push(format!("error: {:#}", err));
+                }
+            }
+            row
+        })
+        .collect();
+
+    println!("{} warmup runs, {} measured runs", options.warmup, options.runs);
+    println!();
+    table::print(
+        &["Year", "Day", "Part", "Variant", "Phase", "Min", "Median", "p95", "Std dev", "Notes"],
+        &rows,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn computes_order_statistics() {
+        // 1 to 20 ms, shuffled.
+        let mut samples: Vec<u64> = (1..=20).collect();
+        samples.rotate_left(7);
+        samples.swap(0, 13);
+        let stats = Stats::from_samples(millis(&samples));
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(10));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+    }
+
+    #[test]
+    fn computes_the_population_standard_deviation() {
+        let stats = Stats::from_samples(millis(&[2, 4, 4, 4, 5, 5, 7, 9]));
+        assert!((stats.std_dev.as_secs_f64() - 0.002).abs() < 1e-9);
+    }
+
+    #[test]
+    fn handles_a_single_sample() {
+        let stats = Stats::from_samples(millis(&[3]));
+        let three = Duration::from_millis(3);
+        assert_eq!((stats.min, stats.median, stats.p95), (three, three, three));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,546 @@
+use crate::bench::BenchOptions;
+use crate::completions::Shell;
+use crate::input::InputSource;
+use crate::log::Level;
+use crate::registry::parse_day;
+use crate::report::ReportOptions;
+use crate::runner::{self, DaySelector};
+use crate::solution::Params;
+
+pub const USAGE: &str = "Usage:
+    cargo run <day> <part> [options]
+    cargo run run <all|dayN|dayA..dayB> [part] [options]
+    cargo run verify <all|dayN|dayA..dayB> [part] [--example <name>]
+    cargo run bench <all|dayN|dayA..dayB> [part] [options]
+    cargo run crosscheck <all|dayN|dayA..dayB> [options]
+    cargo run watch <dayN> [part] [options]
+    cargo run repl <dayN> [options]
+    cargo run new <dayN>
+    cargo run fetch <dayN>
+    cargo run submit <dayN> <part>
+    cargo run list [all|dayN|dayA..dayB]
+    cargo run history <dayN>
+    cargo run report [--html] [--source-url <url>]
+    cargo run explain <dayN> [--dry-run]
+    cargo run completions <bash|zsh|fish>
+    cargo run <dayN> --help
+
+Options:
+    --year <year>     Event year, or all with run, verify, bench, crosscheck and list (default: the latest year)
+    --format <fmt>    Output format for run: table (default) or json, one record per line
+    --input <path>    Read the puzzle input from <path>, or from stdin if <path> is -
+    --example <name>  Use inputs/YYYY/dayN/<name>.txt with its params and answers from examples.toml
+    --param <k>=<v>   Override a puzzle parameter, or part2.<k>=<v> for one part only
+    --jobs <n>        Days run at the same time by run, verify, crosscheck and report (default: one per core)
+    --memory          Count heap usage of each task with run or solve, ranked by peak
+    --profile         Time parse, each part and inner routines, writing folded stacks to profile.folded
+    -v, -vv           Show debug or trace diagnostics from solutions (default: warnings only)
+    --quiet           Show only errors from solutions
+    --log <days>      Only show diagnostics from these days, e.g. day15 or day15,day20
+    --help            Show this message, or a day's tasks and parameters when given a day
+    --warmup <n>      Untimed runs before measuring with bench (default 3)
+    --runs <n>        Measured runs per phase with bench (default 10)
+    --html            Also write report-YYYY.html with report
+    --source-url <u>  Link report's source modules under <u> instead of relative to the repository
+    --dry-run         Print the request explain would send, without sending it
+    --regression <p>  Warn when run or bench is more than <p> percent slower than the recent median (default 25)
+
+Run and bench append their timings to history.jsonl when solving the default input with the
+default parameters and without --memory or --profile.
+
+Completions are for the built binary, e.g. in bash:
+    source <(target/release/advent_of_code completions bash)";
+
+/// The commands, options taking a value and standalone options `parse`
+/// accepts, which the completion scripts offer.
+pub const COMMANDS: &[&str] = &[
+    "run",
+    "verify",
+    "bench",
+    "crosscheck",
+    "watch",
+    "repl",
+    "new",
+    "fetch",
+    "submit",
+    "list",
+    "history",
+    "report",
+    "explain",
+    "completions",
+];
+pub const VALUED_OPTIONS: &[&str] =
+    &["--year", "--format", "--input", "--example", "--param", "--jobs", "--log", "--warmup", "--runs", "--regression", "--source-url"];
+pub const SWITCHES: &[&str] = &["--memory", "--profile", "-v", "-vv", "--quiet", "--html", "--dry-run", "--help"];
+
+pub enum Command {
+    /// The original `cargo run day7 part1` form.
+    Solve { day: String, task: String },
+    Run { selector: DaySelector, task: Option<String> },
+    /// Runs like `Run` and compares answers with `inputs/YYYY/dayN/answers.toml`.
+    Verify { selector: DaySelector, task: Option<String> },
+    /// Times parse and solve separately over repeated runs.
+    Bench { selector: DaySelector, task: Option<String> },
+    /// Runs every variant of each part and compares it with the part's
+    /// reference implementation.
+    Crosscheck { selector: DaySelector },
+    /// Re-runs a day whenever a file in its input directory changes.
+    Watch { day: String, task: Option<String> },
+    /// An interactive shell over one day's parsed input.
+    Repl { day: String },
+    /// Generates the module and input stubs for a day that does not exist yet.
+    New { day: u8 },
+    /// Downloads a day's input into `inputs/YYYY/dayN/input.txt`, see `config.rs`
+    /// for the session token and server.
+    Fetch { day: u8 },
+    /// Solves a part with the default input and posts the answer.
+    Submit { day: String, task: String },
+    /// The registered days with their tasks, parameters and input files.
+    List { selector: DaySelector },
+    /// The recorded timings of a day, see `history.rs`.
+    History { day: u8 },
+    /// Solves the year's default inputs and writes a summary of stars,
+    /// answers and times, see `report.rs`.
+    Report,
+    /// Asks a Messages API endpoint to explain and review a day's solution,
+    /// see `explain.rs` and `config.rs`.
+    Explain { day: u8 },
+    /// Prints a completion script for the shell.
+    Completions { shell: Shell },
+    /// Usage, or the tasks and parameters of one day.
+    Help { day: Option<u8> },
+}
+
+/// The value of `--year`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearChoice {
+    /// The most recent year with solutions.
+    Latest,
+    Year(u16),
+    All,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+pub struct Options {
+    pub year: YearChoice,
+    pub format: Format,
+    pub input: InputSource,
+    pub params: Params,
+    pub bench: BenchOptions,
+    pub report: ReportOptions,
+    /// Whether `explain` only prints its request.
+    pub dry_run: bool,
+    /// How many days `run` and `verify` work on at the same time.
+    pub jobs: usize,
+    jobs_given: bool,
+    /// Whether to count allocations, see `memory.rs`.
+    pub memory: bool,
+    /// Whether to record spans, see `profile.rs`.
+    pub profile: bool,
+    /// The most verbose diagnostics shown, see `log.rs`.
+    pub log_level: Level,
+    /// The days `--log` limits diagnostics to, or every day when empty.
+    pub log_days: Vec<u8>,
+    /// How many percent slower than its baseline a task may get before
+    /// `run` and `bench` warn about it.
+    pub regression: f64,
+    regression_given: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            year: YearChoice::Latest,
+            format: Format::Table,
+            input: InputSource::Default,
+            params: Params::default(),
+            bench: BenchOptions::default(),
+            report: ReportOptions::default(),
+            dry_run: false,
+            jobs: runner::default_jobs(),
+            jobs_given: false,
+            memory: false,
+            profile: false,
+            log_level: Level::Warn,
+            log_days: Vec::new(),
+            regression: 25.0,
+            regression_given: false,
+        }
+    }
+}
+
+fn parse_selector(selector: &str) -> Result<DaySelector, String> {
+    DaySelector::parse(selector).ok_or_else(|| format!("Invalid day selection: {}", selector))
+}
+
+fn parse_count(flag: &str, value: Option<&String>) -> Result<usize, String> {
+    value
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("{} needs a number", flag))
+}
+
+fn parse_days(value: Option<&String>) -> Result<Vec<u8>, String> {
+    let value = value.ok_or("--log needs days, e.g. day15 or day15,day20")?;
+    value
+        .split(',')
+        .map(|day| parse_day(day).ok_or_else(|| format!("Invalid day: {}", day)))
+        .collect()
+}
+
+fn parse_param(value: Option<&String>) -> Result<(&str, i64), String> {
+    let value = value.ok_or("--param needs a name=value pair")?;
+    let (name, number) = value
+        .split_once('=')
+        .ok_or_else(|| format!("--param needs a name=value pair, got {}", value))?;
+    let number = number
+        .parse()
+        .map_err(|_| format!("Value of parameter {} must be an integer, got {}", name, number))?;
+    Ok((name, number))
+}
+
+pub fn parse(args: &[String]) -> Result<(Command, Options), String> {
+    let mut options = Options::default();
+    let mut positional = Vec::new();
+    let mut help = false;
+    let mut verbosity = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "--example" if options.input != InputSource::Default => {
+                return Err(String::from("--input and --example can only be given once, and not together"));
+            }
+            "--input" => {
+                let value = args.next().ok_or("--input needs a path, or - for stdin")?;
+                options.input = InputSource::from_arg(value);
+            }
+            "--example" => {
+                let name = args.next().ok_or("--example needs the name of an example, e.g. example1")?;
+                options.input = InputSource::Example(name.clone());
+            }
+            "--year" => {
+                options.year = match args.next() {
+                    Some(year) if year == "all" => YearChoice::All,
+                    Some(year) => YearChoice::Year(year.parse().map_err(|_| format!("Invalid year: {}", year))?),
+                    None => return Err(String::from("--year needs a year, or all")),
+                };
+            }
+            "--format" => {
+                options.format = match args.next().map(String::as_str) {
+                    Some("table") => Format::Table,
+                    Some("json") => Format::Json,
+                    _ => return Err(String::from("--format needs table or json")),
+                };
+            }
+            "--param" => {
+                let (name, value) = parse_param(args.next())?;
+                options.params.set(name, value);
+            }
+            "--jobs" => {
+                options.jobs = parse_count(arg, args.next())?;
+                options.jobs_given = true;
+                if options.jobs == 0 {
+                    return Err(String::from("--jobs must be at least 1"));
+                }
+            }
+            "--memory" => options.memory = true,
+            "--profile" => options.profile = true,
+            "-v" | "-vv" | "--quiet" if verbosity.is_some() => {
+                return Err(String::from("-v, -vv and --quiet can only be given once"));
+            }
+            "-v" => verbosity = Some(Level::Debug),
+            "-vv" => verbosity = Some(Level::Trace),
+            "--quiet" => verbosity = Some(Level::Error),
+            "--log" => options.log_days = parse_days(args.next())?,
+            "--regression" => {
+                options.regression = args
+                    .next()
+                    .and_then(|value| value.parse().ok())
+                    .filter(|percent: &f64| *percent >= 0.0)
+                    .ok_or("--regression needs a percentage, e.g. 25")?;
+                options.regression_given = true;
+            }
+            "--html" => options.report.html = true,
+            "--source-url" => {
+                let url = args.next().ok_or("--source-url needs the URL the repository is browsable at")?;
+                options.report.source_url = Some(url.clone());
+            }
+            "--dry-run" => options.dry_run = true,
+            "--help" | "-h" => help = true,
+            "--warmup" => options.bench.warmup = parse_count(arg, args.next())?,
+            "--runs" => {
+                options.bench.runs = parse_count(arg, args.next())?;
+                if options.bench.runs == 0 {
+                    return Err(String::from("--runs must be at least 1"));
+                }
+            }
+            flag if flag.starts_with("--") => return Err(format!("Unknown option: {}\n{}", flag, USAGE)),
+            _ => positional.push(arg.clone()),
+        }
+    }
+
+    options.log_level = verbosity.unwrap_or(Level::Warn);
+
+    if help {
+        let day = positional.iter().find_map(|arg| parse_day(arg));
+        if day.is_some() && options.year == YearChoice::All {
+            return Err(String::from("--help for a day needs a single --year"));
+        }
+        return Ok((Command::Help { day }, options));
+    }
+
+    let command = match positional.as_slice() {
+        [command, selector, rest @ ..] if command == "run" && rest.len() <= 1 => {
+            Command::Run { selector: parse_selector(selector)?, task: rest.first().cloned() }
+        }
+        [command, selector, rest @ ..] if command == "verify" && rest.len() <= 1 => {
+            if matches!(options.input, InputSource::File(_) | InputSource::Stdin) {
+                return Err(String::from("--input cannot be used with verify, stored answers belong to the default input or an example"));
+            }
+            if options.params != Params::default() {
+                return Err(String::from("--param cannot be used with verify, stored answers belong to the default parameters"));
+            }
+            Command::Verify { selector: parse_selector(selector)?, task: rest.first().cloned() }
+        }
+        [command, selector, rest @ ..] if command == "bench" && rest.len() <= 1 => {
+            Command::Bench { selector: parse_selector(selector)?, task: rest.first().cloned() }
+        }
+        [command, selector] if command == "crosscheck" => Command::Crosscheck { selector: parse_selector(selector)? },
+        [command, day, rest @ ..] if command == "watch" && rest.len() <= 1 => {
+            if options.input == InputSource::Stdin {
+                return Err(String::from("--input - cannot be used with watch, stdin can only be read once"));
+            }
+            Command::Watch { day: day.clone(), task: rest.first().cloned() }
+        }
+        [command, day] if command == "repl" => Command::Repl { day: day.clone() },
+        [command, day] if command == "new" => {
+            Command::New { day: parse_day(day).ok_or_else(|| format!("Invalid day: {}", day))? }
+        }
+        [command, day] if command == "fetch" => {
+            Command::Fetch { day: parse_day(day).ok_or_else(|| format!("Invalid day: {}", day))? }
+        }
+        [command, day, task] if command == "submit" => {
+            if options.input != InputSource::Default || options.params != Params::default() {
+                return Err(String::from("submit always solves the default input with the default parameters"));
+            }
+            Command::Submit { day: day.clone(), task: task.clone() }
+        }
+        [command, rest @ ..] if command == "list" && rest.len() <= 1 => {
+            let selector = rest.first().map_or(Ok(DaySelector::All), |selector| parse_selector(selector))?;
+            Command::List { selector }
+        }
+        [command, day] if command == "history" => {
+            Command::History { day: parse_day(day).ok_or_else(|| format!("Invalid day: {}", day))? }
+        }
+        [command] if command == "report" => {
+            if options.input != InputSource::Default || options.params != Params::default() {
+                return Err(String::from("report always solves the default inputs with the default parameters"));
+            }
+            Command::Report
+        }
+        [command, day] if command == "explain" => {
+            Command::Explain { day: parse_day(day).ok_or_else(|| format!("Invalid day: {}", day))? }
+        }
+        [command, shell] if command == "completions" => Command::Completions {
+            shell: Shell::parse(shell).ok_or_else(|| format!("Unknown shell {}, expected bash, zsh or fish", shell))?,
+        },
+        [day, task] => Command::Solve { day: day.clone(), task: task.clone() },
+        _ => return Err(String::from(USAGE)),
+    };
+
+    // Submit and report solve too, but only the default inputs, which they check above.
+    let solves = matches!(
+        command,
+        Command::Solve { .. }
+            | Command::Run { .. }
+            | Command::Verify { .. }
+            | Command::Bench { .. }
+            | Command::Crosscheck { .. }
+            | Command::Watch { .. }
+            | Command::Repl { .. }
+    );
+    if !solves && options.input != InputSource::Default {
+        return Err(String::from("--input and --example can only be used when running solutions"));
+    }
+    if !solves && options.params != Params::default() {
+        return Err(String::from("--param can only be used when running solutions"));
+    }
+
+    if options.format == Format::Json && !matches!(command, Command::Solve { .. } | Command::Run { .. }) {
+        return Err(String::from("--format json is only supported when running solutions"));
+    }
+
+    if options.jobs_given
+        && !matches!(command, Command::Run { .. } | Command::Verify { .. } | Command::Crosscheck { .. } | Command::Report)
+    {
+        return Err(String::from("--jobs can only be used with run, verify, crosscheck and report"));
+    }
+
+    if options.report != ReportOptions::default() && !matches!(command, Command::Report) {
+        return Err(String::from("--html and --source-url can only be used with report"));
+    }
+
+    if options.dry_run && !matches!(command, Command::Explain { .. }) {
+        return Err(String::from("--dry-run can only be used with explain"));
+    }
+
+    if options.regression_given && !matches!(command, Command::Run { .. } | Command::Bench { .. }) {
+        return Err(String::from("--regression can only be used with run and bench"));
+    }
+
+    if options.memory && !matches!(command, Command::Run { .. } | Command::Solve { .. }) {
+        return Err(String::from("--memory can only be used when running solutions"));
+    }
+
+    if options.profile && !matches!(command, Command::Run { .. } | Command::Solve { .. }) {
+        return Err(String::from("--profile can only be used when running solutions"));
+    }
+    if options.profile && options.format == Format::Json {
+        return Err(String::from("--profile cannot be combined with --format json"));
+    }
+
+    let many_days = match &command {
+        Command::Run { selector, .. }
+        | Command::Verify { selector, .. }
+        | Command::Bench { selector, .. }
+        | Command::Crosscheck { selector }
+        | Command::List { selector } => options.year == YearChoice::All || !matches!(selector, DaySelector::Day(_)),
+        _ if options.year == YearChoice::All => {
+            return Err(String::from("--year all can only be used with run, verify, bench, crosscheck and list"));
+        }
+        _ => false,
+    };
+    if many_days && matches!(options.input, InputSource::File(_) | InputSource::Stdin) {
+        return Err(String::from("--input can only be used when running a single day"));
+    }
+
+    Ok((command, options))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn parse_line(line: &str) -> Result<(Command, Options), String> {
+        let args: Vec<String> = line.split_whitespace().map(String::from).collect();
+        parse(&args)
+    }
+
+    fn error(line: &str) -> String {
+        match parse_line(line) {
+            Ok(_) => panic!("Expected {} to be rejected", line),
+            Err(err) => err,
+        }
+    }
+
+    #[test]
+    fn parses_the_original_day_and_part_form() {
+        let (command, _) = parse_line("day7 part1").unwrap();
+        assert!(matches!(command, Command::Solve { day, task } if day == "day7" && task == "part1"));
+    }
+
+    #[test]
+    fn parses_run_selections() {
+        let (command, _) = parse_line("run all").unwrap();
+        assert!(matches!(command, Command::Run { selector: DaySelector::All, task: None }));
+
+        let (command, _) = parse_line("run day3..day5 part2").unwrap();
+        assert!(matches!(
+            command,
+            Command::Run { selector: DaySelector::Range(3, 5), task: Some(task) } if task == "part2"
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_commands_and_options() {
+        assert_eq!(error("run"), USAGE);
+        assert_eq!(error("run day1 part1 extra"), USAGE);
+        assert_eq!(error("run day1..dayx"), "Invalid day selection: day1..dayx");
+        assert!(error("run all --fast").starts_with("Unknown option: --fast\n"));
+    }
+
+    #[test]
+    fn rejects_inputs_without_stored_answers_for_verify() {
+        assert_eq!(
+            error("verify day1 --input other.txt"),
+            "--input cannot be used with verify, stored answers belong to the default input or an example"
+        );
+        assert!(parse_line("verify all part1").is_ok());
+    }
+
+    #[test]
+    fn parses_bench_options() {
+        let (_, options) = parse_line("bench day5 --warmup 0 --runs 50").unwrap();
+        assert_eq!((options.bench.warmup, options.bench.runs), (0, 50));
+        assert_eq!(error("bench day5 --runs 0"), "--runs must be at least 1");
+        assert_eq!(error("bench day5 --warmup many"), "--warmup needs a number");
+    }
+
+    #[test]
+    fn selects_examples() {
+        let (_, options) = parse_line("run day18 --example example1").unwrap();
+        assert_eq!(options.input, InputSource::Example(String::from("example1")));
+        assert_eq!(
+            error("run day18 --example example1 --input other.txt"),
+            "--input and --example can only be given once, and not together"
+        );
+        assert_eq!(error("run day18 --example"), "--example needs the name of an example, e.g. example1");
+    }
+
+    #[test]
+    fn parses_parameters() {
+        let (_, options) = parse_line("run day20 --param min_saving=50 --param part2.cheat_length=6").unwrap();
+        assert_eq!(options.params.names().collect::<Vec<_>>(), ["min_saving", "part2.cheat_length"]);
+        assert_eq!(error("run day20 --param min_saving"), "--param needs a name=value pair, got min_saving");
+        assert_eq!(error("run day20 --param size=big"), "Value of parameter size must be an integer, got big");
+        assert_eq!(
+            error("verify day20 --param min_saving=50"),
+            "--param cannot be used with verify, stored answers belong to the default parameters"
+        );
+    }
+
+    #[test]
+    fn parses_years() {
+        let (_, options) = parse_line("run all --year 2023").unwrap();
+        assert_eq!(options.year, YearChoice::Year(2023));
+        let (_, options) = parse_line("run all --year all").unwrap();
+        assert_eq!(options.year, YearChoice::All);
+        let (_, options) = parse_line("day5 part1").unwrap();
+        assert_eq!(options.year, YearChoice::Latest);
+
+        assert_eq!(error("run all --year last"), "Invalid year: last");
+        assert!(error("day5 part1 --year all").starts_with("--year all can only be used with run, verify"));
+        assert_eq!(error("day5 --help --year all"), "--help for a day needs a single --year");
+    }
+
+    #[test]
+    fn watches_files_but_not_stdin() {
+        let (_, options) = parse_line("watch day5 --input other.txt").unwrap();
+        assert_eq!(options.input, InputSource::File(PathBuf::from("other.txt")));
+        assert_eq!(error("watch day5 --input -"), "--input - cannot be used with watch, stdin can only be read once");
+    }
+
+    #[test]
+    fn parses_crosscheck() {
+        let (command, _) = parse_line("crosscheck day2 --year all --jobs 2").unwrap();
+        assert!(matches!(command, Command::Crosscheck { selector: DaySelector::Day(2) }));
+        assert_eq!(error("crosscheck day2 part1"), USAGE);
+    }
+
+    #[test]
+    fn rejects_solving_options_for_commands_that_do_not_solve() {
+        assert_eq!(error("list --param size=7"), "--param can only be used when running solutions");
+        assert_eq!(
+            error("completions bash --example example1"),
+            "--input and --example can only be used when running solutions"
+        );
+        assert!(parse_line("list day3..day5 --year all").is_ok());
+    }
+}
@@ -0,0 +1,368 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::cli;
+use crate::registry::Registry;
+use crate::solution::Task;
+
+/// The shells `completions` writes a script for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl Shell {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "bash" => Some(Shell::Bash),
+            "zsh" => Some(Shell::Zsh),
+            "fish" => Some(Shell::Fish),
+            _ => None,
+        }
+    }
+}
+
+/// The name the scripts complete, that of the built binary.
+const BINARY: &str = "advent_of_code";
+
+/// Commands whose second argument is a task of the day before it.
+const TASK_COMMANDS: &[&str] = &["run", "verify", "bench", "watch", "submit"];
+
+/// What can be typed where, taken from the registry so the scripts offer
+/// exactly the registered days and tasks.
+struct Words {
+    years: Vec<String>,
+    days: Vec<String>,
+    /// The tasks of each registered day, over every year.
+    tasks: Vec<(String, Vec<String>)>,
+    /// The candidates for the argument after each command.
+    arguments: Vec<(&'static str, Vec<String>)>,
+}
+
+impl Words {
+    fn new(registry: &Registry) -> Self {
+        let mut years = BTreeSet::new();
+        let mut tasks: BTreeMap<u8, BTreeSet<Task>> = BTreeMap::new();
+        for solution in registry.iter() {
+            let puzzle = solution.puzzle();
+            years.insert(puzzle.year);
+            tasks.entry(puzzle.day).or_default().extend(solution.tasks());
+        }
+
+        let days: Vec<String> = tasks.keys().map(|day| format!("day{}", day)).collect();
+        let all_days: Vec<String> = (1..=25).map(|day| format!("day{}", day)).collect();
+        let selectors: Vec<String> = std::iter::once(String::from("all")).chain(days.iter().cloned()).collect();
+
+        let arguments = cli::COMMANDS
+            .iter()
+            .map(|&command| {
+                let words = match command {
+                    "run" | "verify" | "bench" | "crosscheck" | "list" => selectors.clone(),
+                    "new" | "fetch" => all_days.clone(),
+                    "completions" => vec![String::from("bash"), String::from("zsh"), String::from("fish")],
+                    "report" => Vec::new(),
+                    _ => days.clone(),
+                };
+                (command, words)
+            })
+            .collect();
+
+        Words {
+            years: years.iter().map(u16::to_string).chain(std::iter::once(String::from("all"))).collect(),
+            tasks: tasks
+                .into_iter()
+                .map(|(day, tasks)| (format!("day{}", day), tasks.iter().map(Task::to_string).collect()))
+                .collect(),
+            days,
+            arguments,
+        }
+    }
+
+    /// Fixed candidates for the value of an option, if it has any.
+    fn values(&self, option: &str) -> Option<Vec<String>> {
+        match option {
+            "--year" => Some(self.years.clone()),
+            "--format" => Some(vec![String::from("table"), String::from("json")]),
+            _ => None,
+        }
+    }
+
+    fn first(&self) -> Vec<String> {
+        cli::COMMANDS.iter().map(|command| command.to_string()).chain(self.days.iter().cloned()).collect()
+    }
+
+    fn options(&self) -> Vec<&'static str> {
+        cli::VALUED_OPTIONS.iter().chain(cli::SWITCHES).copied().collect()
+    }
+}
+
+/// The completion script for `shell`, to be sourced or installed where the
+/// shell looks for completions.
+pub fn script(registry: &Registry, shell: Shell) -> String {
+    let words = Words::new(registry);
+    match shell {
+        Shell::Bash => bash(&words),
+        Shell::Zsh => zsh(&words),
+        Shell::Fish => fish(&words),
+    }
+}
+
+/// One line per `(pattern, action)` pair, each indented by `indent` and
+/// formatted with `arm`.
+fn arms<'a>(
+    indent: usize,
+    pairs: impl IntoIterator<Item = (&'a str, String)>,
+    arm: fn(&str, &str) -> String,
+) -> String {
+    pairs
+        .into_iter()
+        .map(|(pattern, action)| format!("{:indent$}{}\n", "", arm(pattern, &action), indent = indent))
+        .collect()
+}
+
+/// The shell snippet completing the value of each option: its fixed values,
+/// file names for `--input`, or nothing.
+fn option_values(words: &Words, values: fn(&str) -> String, files: &str, none: &str) -> Vec<(&'static str, String)> {
+    cli::VALUED_OPTIONS
+        .iter()
+        .map(|&option| match words.values(option) {
+            Some(candidates) => (option, values(&candidates.join(" "))),
+            None if option == "--input" => (option, files.to_string()),
+            None => (option, none.to_string()),
+        })
+        .collect()
+}
+
+fn bash(words: &Words) -> String {
+    let case_arm = |pattern: &str, action: &str| format!("{}) {} ;;", pattern, action);
+    let tasks = words.tasks.iter().map(|(day, tasks)| (day.as_str(), format!("echo \"{}\"", tasks.join(" "))));
+    let tasks = arms(8, tasks, case_arm);
+    let values = arms(
+        8,
+        option_values(
+            words,
+            |candidates| format!("COMPREPLY=($(compgen -W \"{}\" -- \"$cur\")); return", candidates),
+            "COMPREPLY=($(compgen -f -- \"$cur\")); return",
+            "return",
+        ),
+        case_arm,
+    );
+    let arguments = arms(
+        16,
+        words
+            .arguments
+            .iter()
+            .map(|(command, candidates)| (*command, format!("candidates=\"{}\"", candidates.join(" ")))),
+        case_arm,
+    );
+
+    format!(
+        "# bash completion for {BINARY}, generated by `{BINARY} completions bash`
+
+_{BINARY}_tasks() {{
+    case \"$1\" in
+{tasks}    esac
+}}
+
+_{BINARY}() {{
+    local cur=\"${{COMP_WORDS[COMP_CWORD]}}\" prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"
+    local candidates=\"\" positional=() i
+
+    case \"$prev\" in
+{values}    esac
+
+    if [[ \"$cur\" == -* ]]; then
+        COMPREPLY=($(compgen -W \"{options}\" -- \"$cur\"))
+        return
+    fi
+
+    for ((i = 1; i < COMP_CWORD; i++)); do
+        case \"${{COMP_WORDS[i]}}\" in
+            {valued}) ((i++)) ;;
+            -*) ;;
+            *) positional+=(\"${{COMP_WORDS[i]}}\") ;;
+        esac
+    done
+
+    case \"${{#positional[@]}}\" in
+        0) candidates=\"{first}\" ;;
+        1)
+            case \"${{positional[0]}}\" in
+{arguments}                *) candidates=\"$(_{BINARY}_tasks \"${{positional[0]}}\")\" ;;
+            esac
+            ;;
+        2)
+            case \"${{positional[0]}}\" in
+                {task_commands}) candidates=\"$(_{BINARY}_tasks \"${{positional[1]}}\")\" ;;
+            esac
+            ;;
+    esac
+    COMPREPLY=($(compgen -W \"$candidates\" -- \"$cur\"))
+}}
+
+complete -F _{BINARY} {BINARY}
+",
+        options = words.options().join(" "),
+        valued = cli::VALUED_OPTIONS.join("|"),
+        first = words.first().join(" "),
+        task_commands = TASK_COMMANDS.join("|"),
+    )
+}
+
+fn zsh(words: &Words) -> String {
+    let case_arm = |pattern: &str, action: &str| format!("{}) {} ;;", pattern, action);
+    let tasks = words.tasks.iter().map(|(day, tasks)| (day.as_str(), format!("compadd -- {}", tasks.join(" "))));
+    let tasks = arms(8, tasks, case_arm);
+    let values = arms(
+        8,
+        option_values(words, |candidates| format!("compadd -- {}; return", candidates), "_files; return", "return"),
+        case_arm,
+    );
+    let arguments = arms(
+        16,
+        words.arguments.iter().map(|(command, candidates)| (*command, format!("compadd -- {}", candidates.join(" ")))),
+        case_arm,
+    );
+
+    // Installed in $fpath the file is the body of an autoloaded function,
+    // sourced it has to register itself.
+    format!(
+        "#compdef {BINARY}
+# zsh completion for {BINARY}, generated by `{BINARY} completions zsh`
+
+_{BINARY}_tasks() {{
+    case $1 in
+{tasks}    esac
+}}
+
+_{BINARY}() {{
+    local -a positional
+    local i
+
+    case ${{words[CURRENT-1]}} in
+{values}    esac
+
+    if [[ $PREFIX == -* ]]; then
+        compadd -- {options}
+        return
+    fi
+
+    for ((i = 2; i < CURRENT; i++)); do
+        case ${{words[i]}} in
+            {valued}) ((i++)) ;;
+            -*) ;;
+            *) positional+=(${{words[i]}}) ;;
+        esac
+    done
+
+    case ${{#positional}} in
+        0) compadd -- {first} ;;
+        1)
+            case ${{positional[1]}} in
+{arguments}                *) _{BINARY}_tasks ${{positional[1]}} ;;
+            esac
+            ;;
+        2)
+            case ${{positional[1]}} in
+                {task_commands}) _{BINARY}_tasks ${{positional[2]}} ;;
+            esac
+            ;;
+    esac
+}}
+
+if [[ $zsh_eval_context[-1] == loadautofunc ]]; then
+    _{BINARY} \"$@\"
+else
+    compdef _{BINARY} {BINARY}
+fi
+",
+        options = words.options().join(" "),
+        valued = cli::VALUED_OPTIONS.join("|"),
+        first = words.first().join(" "),
+        task_commands = TASK_COMMANDS.join("|"),
+    )
+}
+
+/// How fish names an option: `-l` for `--long`, `-o` for `-old` style.
+fn fish_option(option: &str) -> String {
+    match option.strip_prefix("--") {
+        Some(name) => format!("-l {}", name),
+        None => format!("-o {}", option.trim_start_matches('-')),
+    }
+}
+
+fn fish(words: &Words) -> String {
+    let switch_arm = |pattern: &str, action: &str| format!("case {}\n{:4}{}", pattern, "", action);
+    let tasks = arms(
+        8,
+        words.tasks.iter().map(|(day, tasks)| (day.as_str(), format!("        printf '%s\\n' {}", tasks.join(" ")))),
+        switch_arm,
+    );
+    let arguments = arms(
+        16,
+        words
+            .arguments
+            .iter()
+            .map(|(command, candidates)| {
+                (*command, format!("                printf '%s\\n' {}", candidates.join(" ")))
+            }),
+        switch_arm,
+    );
+    let options: String = option_values(words, |candidates| format!("-x -a '{}'", candidates), "-r -F", "-x")
+        .into_iter()
+        .map(|(option, action)| format!("complete -c {BINARY} {} {}\n", fish_option(option), action))
+        .chain(cli::SWITCHES.iter().map(|option| format!("complete -c {BINARY} {}\n", fish_option(option))))
+        .collect();
+
+    format!(
+        "# fish completion for {BINARY}, generated by `{BINARY} completions fish`
+
+function __{BINARY}_tasks
+    switch $argv[1]
+{tasks}    end
+end
+
+function __{BINARY}_positional
+    set -l skip 0
+    for token in (commandline -opc)[2..-1]
+        if test $skip = 1
+            set skip 0
+            continue
+        end
+        switch $token
+            case {valued}
+                set skip 1
+            case '-*'
+            case '*'
+                echo $token
+        end
+    end
+end
+
+function __{BINARY}_candidates
+    set -l positional (__{BINARY}_positional)
+    switch (count $positional)
+        case 0
+            printf '%s\\n' {first}
+        case 1
+            switch $positional[1]
+{arguments}                case '*'
+                    __{BINARY}_tasks $positional[1]
+            end
+        case 2
+            switch $positional[1]
+                case {task_commands}
+                    __{BINARY}_tasks $positional[2]
+            end
+    end
+end
+
+complete -c {BINARY} -f
+complete -c {BINARY} -n 'not string match -q -- \"-*\" (commandline -ct)' -a '(__{BINARY}_candidates)'
+{options}",
+        valued = cli::VALUED_OPTIONS.join(" "),
+        first = words.first().join(" "),
+        task_commands = TASK_COMMANDS.join(" "),
+    )
+}
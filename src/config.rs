@@ -0,0 +1,94 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use clust::messages::ClaudeModel;
+use serde::Deserialize;
+
+/// Settings for commands that talk to the puzzle server or, for `explain`,
+/// to a Messages API endpoint, read from `aoc.toml` in the working
+/// directory. Environment variables take precedence over the file:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."   # AOC_SESSION
+/// base_url = "http://localhost:8000" # AOC_BASE_URL
+///
+/// api_key = "sk-ant-..."                 # ANTHROPIC_API_KEY
+/// api_base_url = "http://localhost:8001" # ANTHROPIC_BASE_URL
+/// model = "claude-3-5-sonnet-20240620"   # ANTHROPIC_MODEL
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    pub api_key: Option<String>,
+    pub api_base_url: Option<String>,
+    pub model: Option<String>,
+}
+
+pub const PATH: &str = "aoc.toml";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub const DEFAULT_API_BASE_URL: &str = "https://api.anthropic.com";
+
+impl Config {
+    pub fn load() -> Result<Self> {
+        let mut config = Config::read(Path::new(PATH))?;
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        if let Ok(api_key) = env::var("ANTHROPIC_API_KEY") {
+            config.api_key = Some(api_key);
+        }
+        if let Ok(api_base_url) = env::var("ANTHROPIC_BASE_URL") {
+            config.api_base_url = Some(api_base_url);
+        }
+        if let Ok(model) = env::var("ANTHROPIC_MODEL") {
+            config.model = Some(model);
+        }
+
+        Ok(config)
+    }
+
+    fn read(path: &Path) -> Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(err) => return Err(err).with_context(|| format!("Failed to read {}", path.display())),
+        };
+
+        toml::from_str(&contents).with_context(|| format!("Invalid config file {}", path.display()))
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL).trim_end_matches('/')
+    }
+
+    pub fn api_base_url(&self) -> &str {
+        self.api_base_url.as_deref().unwrap_or(DEFAULT_API_BASE_URL).trim_end_matches('/')
+    }
+
+    /// The model `explain` asks, by default the newest one `clust` knows.
+    pub fn model(&self) -> String {
+        self.model.clone().unwrap_or_else(|| ClaudeModel::Claude35Sonnet20240620.to_string())
+    }
+
+    pub fn api_key(&self) -> Result<&str> {
+        self.api_key
+            .as_deref()
+            .with_context(|| format!("No API key, set ANTHROPIC_API_KEY or api_key in {}", PATH))
+    }
+
+    pub fn session(&self) -> Result<&str> {
+        self.session
+            .as_deref()
+            .with_context(|| format!("No session token, set AOC_SESSION or session in {}", PATH))
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::input::InputSource;
+use crate::registry::{DynSolution, Registry};
+use crate::runner::{self, Outcome, Selection};
+use crate::solution::{Answer, Params, Part, Puzzle};
+use crate::table;
+
+pub enum Verdict {
+    Agrees,
+    Differs { reference: String, actual: String },
+    Failed(String),
+    /// The reference implementation failed, so there is nothing to compare with.
+    NoReference(String),
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Verdict::Agrees)
+    }
+}
+
+/// One alternative implementation compared with its part's reference.
+pub struct Check {
+    pub outcome: Outcome,
+    pub verdict: Verdict,
+}
+
+/// Runs the reference implementation and every variant of each part of the
+/// selected days that have variants, all on the same parsed input, and
+/// compares each variant's answer with the reference's.
+pub fn crosscheck(
+    registry: &Registry,
+    selection: Selection,
+    source: &InputSource,
+    overrides: &Params,
+    jobs: usize,
+) -> Vec<Check> {
+    let solutions: Vec<Arc<dyn DynSolution>> = registry
+        .shared()
+        .filter(|solution| selection.contains(solution.puzzle()))
+        .filter(|solution| solution.tasks().iter().any(|task| task.variant.is_some()))
+        .collect();
+
+    let outcomes = runner::run_solutions(solutions, None, source, overrides, jobs);
+
+    let references: BTreeMap<(Puzzle, Part), Result<String, String>> = outcomes
+        .iter()
+        .filter(|outcome| outcome.task.variant.is_none())
+        .map(|outcome| {
+            let answer = match &outcome.answer {
+                Ok(answer) => Ok(answer.to_string()),
+                Err(err) => Err(format!("{:#}", err)),
+            };
+            ((outcome.puzzle, outcome.task.part), answer)
+        })
+        .collect();
+
+    outcomes
+        .into_iter()
+        .filter(|outcome| outcome.task.variant.is_some())
+        .map(|outcome| {
+            let reference = references[&(outcome.puzzle, outcome.task.part)].clone();
+            let verdict = verdict(reference, &outcome.answer);
+            Check { outcome, verdict }
+        })
+        .collect()
+}
+
+/// Compares a variant's answer with the reference answer of its part, or
+/// the reference's error.
+fn verdict(reference: Result<String, String>, answer: &Result<Answer>) -> Verdict {
+    match (reference, answer) {
+        (Err(err), _) => Verdict::NoReference(err),
+        (Ok(_), Err(err)) => Verdict::Failed(format!("{:#}", err)),
+        (Ok(reference), Ok(answer)) if answer.to_string() == reference => Verdict::Agrees,
+        (Ok(reference), Ok(answer)) => Verdict::Differs { reference, actual: answer.to_string() },
+    }
+}
+
+pub fn print_report(checks: &[Check]) {
+    let rows: Vec<Vec<String>> = checks
+        .iter()
+        .map(|check| {
+            let outcome = &check.outcome;
+            let (status, detail) = match &check.verdict {
+                Verdict::Agrees => (
+                    "agrees",
+                    outcome.answer.as_ref().map(|a| a.to_string()).unwrap_or_default(),
+                ),
+                Verdict::Differs { reference, actual } => {
+                    ("DIFFERS", format!("reference {} answered {}, got {}", outcome.task.part, reference, actual))
+                }
+                Verdict::Failed(err) => ("ERROR", err.clone()),
+                Verdict::NoReference(err) => ("ERROR", format!("reference failed: {}", err)),
+            };
+
+            vec![
+                outcome.puzzle.year.to_string(),
+                outcome.puzzle.day.to_string(),
+                outcome.task.part.number().to_string(),
+                outcome.task.variant.unwrap_or("-").to_string(),
+                status.to_string(),
+                detail,
+            ]
+        })
+        .collect();
+
+    table::print(&["Year", "Day", "Part", "Variant", "Result", "Details"], &rows);
+
+    let count = |f: fn(&Verdict) -> bool| checks.iter().filter(|check| f(&check.verdict)).count();
+    println!();
+    println!(
+        "{} agree, {} differ, {} failed",
+        count(|v| matches!(v, Verdict::Agrees)),
+        count(|v| matches!(v, Verdict::Differs { .. })),
+        count(|v| matches!(v, Verdict::Failed(_) | Verdict::NoReference(_))),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+
+    #[test]
+    fn agrees_on_the_same_answer() {
+        assert!(matches!(verdict(Ok(String::from("42")), &Ok(Answer::from(42u64))), Verdict::Agrees));
+        assert!(matches!(verdict(Ok(String::from("42")), &Ok(Answer::from(42i64))), Verdict::Agrees));
+    }
+
+    #[test]
+    fn differs_on_another_answer() {
+        match verdict(Ok(String::from("42")), &Ok(Answer::from(41u64))) {
+            Verdict::Differs { reference, actual } => assert_eq!((reference.as_str(), actual.as_str()), ("42", "41")),
+            _ => panic!("Expected the answers to differ"),
+        }
+    }
+
+    #[test]
+    fn fails_a_failing_variant() {
+        match verdict(Ok(String::from("42")), &Err(anyhow!("Overflow"))) {
+            Verdict::Failed(err) => assert_eq!(err, "Overflow"),
+            _ => panic!("Expected a failure"),
+        }
+    }
+
+    #[test]
+    fn has_nothing_to_compare_with_when_the_reference_fails() {
+        let verdict = verdict(Err(String::from("Overflow")), &Ok(Answer::from(42u64)));
+        assert!(matches!(verdict, Verdict::NoReference(err) if err == "Overflow"));
+    }
+}
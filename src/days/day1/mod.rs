@@ -1,2 +0,0 @@
-pub mod part1;
-pub mod part2;
\ No newline at end of file
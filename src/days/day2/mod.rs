@@ -1,4 +0,0 @@
-pub mod part1;
-pub mod part2;
-pub mod part1_optimized;
-pub mod part2_optimized;
\ No newline at end of file
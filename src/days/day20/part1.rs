@@ -1,14 +0,0 @@
-
-use super::utils::read_input;
-
-pub fn solve() -> Result<(), Box<dyn std::error::Error>> {
-    let grid = read_input().expect("Input not found");
-
-    let cheats = grid.find_cheats(100, 2);
-
-    let num_min_duration = cheats.values().sum::<usize>();
-    
-    println!("Day 20 Part 1: {:?}", num_min_duration);
-    
-    Ok(())
-}
\ No newline at end of file
@@ -1,4 +0,0 @@
-mod input;
-mod topological_sort;
-pub mod part1;
-pub mod part2;
\ No newline at end of file
@@ -0,0 +1,197 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// The ways a day can fail, so the runner can explain a failure instead of
+/// only passing a panic message along.
+#[derive(Debug)]
+pub enum Error {
+    Io { path: PathBuf, source: io::Error },
+    /// The input does not have the shape the day expects.
+    Parse(ParseError),
+    /// A solution reached a state the puzzle rules out, such as a robot
+    /// pushing into another robot.
+    InvalidState(String),
+    /// The input is well formed but has no answer, such as a maze with no
+    /// path to the exit.
+    NoSolution(String),
+}
+
+/// Where in the input parsing failed.
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    /// The input file, which the runner fills in as parsers only see the text.
+    pub file: Option<String>,
+    /// 1-based, as are `column`s.
+    pub line: usize,
+    pub column: usize,
+    /// The offending text, empty when something is missing at the end of a line.
+    pub text: String,
+    /// The whole line, for pointing at `text`.
+    pub line_text: String,
+    pub message: String,
+}
+
+impl Error {
+    /// A parse error pointing at `at`, which must be a slice of `input`.
+    pub fn parse(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset + at.len() <= input.len())
+            .expect("Parse errors point into the input");
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |newline| offset + newline);
+
+        Error::Parse(ParseError {
+            file: None,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: at.to_string(),
+            line_text: input[line_start..line_end].trim_end_matches('\r').to_string(),
+            message: message.into(),
+        })
+    }
+
+    /// A parse error for a missing piece, pointing just past the end of `after`,
+    /// which must be a slice of `input`.
+    pub fn missing(input: &str, after: &str, message: impl Into<String>) -> Self {
+        Error::parse(input, &after[after.len()..], message)
+    }
+}
+
+/// Parses `text`, a slice of `input`, pointing at it if it is not a valid `T`.
+pub fn parse_at<T: FromStr>(input: &str, text: &str, what: &str) -> Result<T, Error> {
+    text.parse().map_err(|_| Error::parse(input, text, format!("Expected {}", what)))
+}
+
+impl ParseError {
+    fn location(&self) -> String {
+        match &self.file {
+            Some(file) => format!("{}:{}:{}", file, self.line, self.column),
+            None => format!("line {}, column {}", self.line, self.column),
+        }
+    }
+
+    /// The error with the offending line quoted and the text underlined.
+    pub fn diagnostic(&self) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let underline = "^".repeat(self.text.chars().count().max(1));
+        format!(
+            "{}\n{}--> {}\n{} |\n{} | {}\n{} | {}{}",
+            self.message,
+            gutter,
+            self.location(),
+            gutter,
+            self.line,
+            self.line_text,
+            gutter,
+            " ".repeat(self.column - 1),
+            underline
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}", self.message, self.location())?;
+        match self.text.as_str() {
+            "" => write!(f, ", found the end of the line"),
+            text => write!(f, ", found {:?}", text),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            // The cause follows as the source, as with `anyhow::Context`.
+            Error::Io { path, .. } => write!(f, "Failed to read {}", path.display()),
+            Error::Parse(err) => write!(f, "{}", err),
+            Error::InvalidState(message) => write!(f, "Invalid puzzle state: {}", message),
+            Error::NoSolution(message) => write!(f, "No solution: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(err: Error) -> ParseError {
+        match err {
+            Error::Parse(err) => err,
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn locates_text_on_a_later_line() {
+        let input = "1 2\n3 4 x\n5 6";
+        let at = &input[8..9];
+        let err = parse_error(Error::parse(input, at, "Expected a number"));
+
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.text, "x");
+        assert_eq!(err.line_text, "3 4 x");
+        assert_eq!(err.to_string(), "Expected a number at line 2, column 5, found \"x\"");
+    }
+
+    #[test]
+    fn counts_columns_in_characters() {
+        let input = "#.\n#éé?#";
+        let offset = input.find('?').unwrap();
+        let err = parse_error(Error::parse(input, &input[offset..offset + 1], "Expected a tile"));
+
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(
+            err.diagnostic(),
+            "Expected a tile\n --> line 2, column 4\n  |\n2 | #éé?#\n  |    ^"
+        );
+    }
+
+    #[test]
+    fn points_past_the_end_of_a_line_when_missing() {
+        let input = "Register A: 1\nRegister B\n";
+        let line = input.lines().nth(1).unwrap();
+        let mut err = parse_error(Error::missing(input, line, "Expected a value"));
+        err.file = Some(String::from("input.txt"));
+
+        assert_eq!((err.line, err.column), (2, 11));
+        assert_eq!(err.text, "");
+        assert_eq!(err.to_string(), "Expected a value at input.txt:2:11, found the end of the line");
+        assert_eq!(
+            err.diagnostic(),
+            "Expected a value\n --> input.txt:2:11\n  |\n2 | Register B\n  |           ^"
+        );
+    }
+
+    #[test]
+    fn underlines_the_whole_text() {
+        let input = "a\nb\nc\nd\ne\nf\ng\nh\ni\nlong token";
+        let offset = input.find("token").unwrap();
+        let err = parse_error(Error::parse(input, &input[offset..], "Expected a number"));
+
+        assert_eq!(
+            err.diagnostic(),
+            "Expected a number\n  --> line 10, column 6\n   |\n10 | long token\n   |      ^^^^^"
+        );
+    }
+
+    #[test]
+    fn strips_carriage_returns_from_the_quoted_line() {
+        let input = "ab\r\ncd";
+        let err = parse_error(Error::parse(input, &input[1..2], "Expected a"));
+        assert_eq!(err.line_text, "ab");
+    }
+}
@@ -0,0 +1,176 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Result};
+use toml::{Table, Value};
+
+use crate::answers::Answers;
+use crate::input;
+use crate::solution::{Params, Puzzle};
+
+/// A named example input, `inputs/YYYY/dayN/<name>.txt`, with the parameters
+/// and expected answers listed for it in `examples.toml` in the same directory:
+///
+/// ```toml
+/// [example1]
+/// part1 = 22
+/// part2 = "6,1"
+///
+/// [example1.params]
+/// size = 7
+/// bytes = 12
+/// part2.min_saving = 50
+/// ```
+#[derive(Debug, Default)]
+pub struct Example {
+    pub params: Params,
+    pub answers: Answers,
+}
+
+impl Example {
+    /// Loads what `examples.toml` says about example `name` of `puzzle`. An
+    /// example that is not listed there runs with the default parameters and
+    /// has no known answers.
+    pub fn load(puzzle: Puzzle, name: &str) -> Result<Self> {
+        let path = config_path(puzzle);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Example::default()),
+            Err(err) => return Err(err).with_context(|| format!("Failed to read {}", path.display())),
+        };
+
+        Example::parse(&contents, name).with_context(|| format!("Invalid examples file {}", path.display()))
+    }
+
+    pub fn parse(contents: &str, name: &str) -> Result<Self> {
+        let mut table: Table = contents.parse()?;
+
+        let mut section = match table.remove(name) {
+            Some(Value::Table(section)) => section,
+            Some(other) => return Err(anyhow!("Example {} must be a table, found {}", name, other.type_str())),
+            None => return Ok(Example::default()),
+        };
+
+        let params = match section.remove("params") {
+            Some(Value::Table(params)) => parse_params(name, params)?,
+            Some(other) => return Err(anyhow!("Params of {} must be a table, found {}", name, other.type_str())),
+            None => Params::default(),
+        };
+
+        Ok(Example { params, answers: Answers::from_table(section)? })
+    }
+}
+
+fn parse_params(name: &str, table: Table) -> Result<Params> {
+    let mut params = Params::default();
+    for (key, value) in table {
+        match value {
+            Value::Integer(value) => params.set(&key, value),
+            Value::Table(part) if key == "part1" || key == "part2" => {
+                for (part_key, value) in part {
+                    let key = format!("{}.{}", key, part_key);
+                    match value {
+                        Value::Integer(value) => params.set(&key, value),
+                        other => return Err(param_type_error(name, &key, other)),
+                    }
+                }
+            }
+            other => return Err(param_type_error(name, &key, other)),
+        }
+    }
+    Ok(params)
+}
+
+fn param_type_error(name: &str, key: &str, value: Value) -> anyhow::Error {
+    anyhow!("Param {} of {} must be an integer, found {}", key, name, value.type_str())
+}
+
+/// The examples of `puzzle`: every `.txt` file in its input directory other
+/// than the real input, sorted by name.
+pub fn names(puzzle: Puzzle) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(input::dir(puzzle)) else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?.strip_suffix(".txt")?;
+            (name != "input").then(|| name.to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+pub fn path(puzzle: Puzzle, name: &str) -> PathBuf {
+    input::dir(puzzle).join(format!("{}.txt", name))
+}
+
+fn config_path(puzzle: Puzzle) -> PathBuf {
+    input::dir(puzzle).join("examples.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::Registry;
+    use crate::solution::{Part, Task};
+
+    const EXAMPLES: &str = "
+[example1]
+part1 = 22
+part2 = \"6,1\"
+
+[example1.params]
+size = 7
+bytes = 12
+
+[example2.params]
+size = 5
+part2.min_saving = 50
+";
+
+    const DAY18_EXAMPLE: &str = "5,4\n4,2\n4,5\n3,0\n2,1\n6,3\n2,4\n1,5\n0,6\n3,3\n2,6\n5,1\n1,2\n5,5\n2,5\n6,5\n1,4\n\
+                                 0,4\n6,4\n1,1\n6,1\n1,0\n0,5\n1,6\n2,0\n";
+
+    #[test]
+    fn selects_an_example_by_name() {
+        let example = Example::parse(EXAMPLES, "example1").unwrap();
+        assert_eq!(example.params.names().collect::<Vec<_>>(), ["bytes", "size"]);
+        assert_eq!(example.answers.get(Task::new(Part::Two, None)), Some("6,1"));
+
+        let example = Example::parse(EXAMPLES, "example2").unwrap();
+        assert_eq!(example.params.names().collect::<Vec<_>>(), ["part2.min_saving", "size"]);
+        assert_eq!(example.answers.get(Task::new(Part::One, None)), None);
+    }
+
+    #[test]
+    fn runs_unlisted_examples_with_defaults() {
+        let example = Example::parse(EXAMPLES, "example3").unwrap();
+        assert_eq!(example.params, Params::default());
+        assert_eq!(example.answers.get(Task::new(Part::One, None)), None);
+    }
+
+    #[test]
+    fn rejects_malformed_sections() {
+        let err = Example::parse("example1 = 3", "example1").unwrap_err();
+        assert_eq!(err.to_string(), "Example example1 must be a table, found integer");
+        let err = Example::parse("[example1.params]\nsize = \"7\"", "example1").unwrap_err();
+        assert_eq!(err.to_string(), "Param size of example1 must be an integer, found string");
+        assert!(Example::parse("[example1", "example1").is_err());
+    }
+
+    #[test]
+    fn solves_day_18_at_the_example_size() {
+        let example = Example::parse(EXAMPLES, "example1").unwrap();
+        let registry = Registry::new();
+        let solution = registry.get(Puzzle::new(2024, 18)).unwrap();
+        let input = solution.parse(DAY18_EXAMPLE).unwrap();
+
+        let answer = |part| solution.solve(input.as_ref(), Task::new(part, None), &example.params).unwrap().to_string();
+        assert_eq!(answer(Part::One), "22");
+        assert_eq!(answer(Part::Two), "6,1");
+    }
+}
@@ -0,0 +1,154 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use clust::messages::{Content, ContentBlock, Message, SystemPrompt};
+use clust::{ApiErrorResponse, Version};
+use reqwest::header::{CONTENT_TYPE, USER_AGENT};
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::fetch::AGENT;
+use crate::input;
+use crate::solution::Puzzle;
+
+/// The longest explanation asked for, in tokens.
+const MAX_TOKENS: u32 = 2048;
+
+const SYSTEM_PROMPT: &str = "You review solutions to Advent of Code puzzles. Explain the approach the \
+code takes and why it works, then point out bugs, inputs that would break it and needless work. Be \
+concise and refer to functions by name.";
+
+/// A Messages API request with the model as given in the config rather than
+/// one of the models `clust` knows.
+#[derive(Debug, Serialize)]
+pub struct Request {
+    model: String,
+    max_tokens: u32,
+    system: SystemPrompt,
+    messages: Vec<Message>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Response {
+    content: Content,
+}
+
+fn source_dir(puzzle: Puzzle) -> PathBuf {
+    PathBuf::from(format!("src/years/y{}/day{}", puzzle.year, puzzle.day))
+}
+
+/// Every `.rs` file of the day's module, each under a header with its path.
+fn source(puzzle: Puzzle) -> Result<String> {
+    let dir = source_dir(puzzle);
+    let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read {}, explain runs from the repository root", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "rs"))
+        .collect();
+    paths.sort();
+
+    let mut source = String::new();
+    for path in paths {
+        let contents = fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        source.push_str(&format!("// {}\n```rust\n{}\n```\n\n", path.display(), contents.trim_end()));
+    }
+    Ok(source)
+}
+
+/// `inputs/YYYY/dayN/statement.md`, a short summary of the puzzle kept next
+/// to its input.
+fn statement_path(puzzle: Puzzle) -> PathBuf {
+    input::dir(puzzle).join("statement.md")
+}
+
+/// The request asking for an explanation and review of `puzzle`'s solution.
+pub fn request(config: &Config, puzzle: Puzzle) -> Result<Request> {
+    let path = statement_path(puzzle);
+    let statement = match fs::read_to_string(&path) {
+        Ok(statement) => statement.trim().to_string(),
+        Err(_) => format!(
+            "Not available, infer it from the code. The puzzle is at https://adventofcode.com/{}/day/{}.",
+            puzzle.year, puzzle.day
+        ),
+    };
+
+    let prompt = format!(
+        "Advent of Code {} day {}.\n\nProblem statement:\n{}\n\nSolution:\n\n{}",
+        puzzle.year,
+        puzzle.day,
+        statement,
+        source(puzzle)?
+    );
+
+    Ok(Request {
+        model: config.model(),
+        max_tokens: MAX_TOKENS,
+        system: SystemPrompt::new(SYSTEM_PROMPT),
+        messages: vec![Message::user(prompt.as_str())],
+    })
+}
+
+fn url(config: &Config) -> String {
+    format!("{}/v1/messages", config.api_base_url())
+}
+
+/// The request as it would be sent, with the API key left out.
+pub fn describe(config: &Config, request: &Request) -> Result<String> {
+    let key = if config.api_key.is_some() { "<set>" } else { "<not set>" };
+    Ok(format!(
+        "POST {}\nx-api-key: {}\nanthropic-version: {}\ncontent-type: application/json\n\n{}",
+        url(config),
+        key,
+        Version::default(),
+        serde_json::to_string_pretty(request)?
+    ))
+}
+
+async fn send(config: &Config, request: &Request) -> Result<String> {
+    let url = url(config);
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .header(USER_AGENT, AGENT)
+        .header("x-api-key", config.api_key()?)
+        .header("anthropic-version", Version::default().to_string())
+        .header(CONTENT_TYPE, "application/json")
+        .body(serde_json::to_string(request)?)
+        .send()
+        .await
+        .with_context(|| format!("Failed to post to {}", url))?;
+
+    let status = response.status();
+    let body = response.text().await.with_context(|| format!("Failed to read the response from {}", url))?;
+    if !status.is_success() {
+        match serde_json::from_str::<ApiErrorResponse>(&body) {
+            Ok(error) => bail!("{} answered {}: {}", url, status, error.error.message),
+            Err(_) => bail!("{} answered {}", url, status),
+        }
+    }
+
+    let response: Response =
+        serde_json::from_str(&body).with_context(|| format!("Unexpected response from {}", url))?;
+    let text = match response.content {
+        Content::SingleText(text) => text,
+        Content::MultipleBlocks(blocks) => blocks
+            .into_iter()
+            .filter_map(|block| match block {
+                ContentBlock::Text(block) => Some(block.text),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    };
+    Ok(text)
+}
+
+/// Sends `request` and returns the text of the reply.
+pub fn explain(config: &Config, request: &Request) -> Result<String> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .context("Failed to start the async runtime")?;
+    runtime.block_on(send(config, request))
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use reqwest::header::{COOKIE, USER_AGENT};
+use reqwest::StatusCode;
+
+use crate::config::Config;
+use crate::input;
+use crate::solution::Puzzle;
+
+/// Identifies the tool to the puzzle server, as its maintainers ask for.
+pub const AGENT: &str = concat!(
+    "advent_of_code/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/manojlds/advent_of_code)"
+);
+
+pub enum Fetched {
+    Downloaded(PathBuf),
+    Cached(PathBuf),
+}
+
+async fn download(config: &Config, puzzle: Puzzle) -> Result<String> {
+    let url = format!("{}/{}/day/{}/input", config.base_url(), puzzle.year, puzzle.day);
+
+    let response = reqwest::Client::new()
+        .get(&url)
+        .header(USER_AGENT, AGENT)
+        .header(COOKIE, format!("session={}", config.session()?))
+        .send()
+        .await
+        .with_context(|| format!("Failed to request {}", url))?;
+
+    match response.status() {
+        StatusCode::OK => response.text().await.with_context(|| format!("Failed to read the response from {}", url)),
+        StatusCode::NOT_FOUND => bail!("The input for {} is not available yet", puzzle),
+        StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            bail!("{} rejected the session token, it may have expired", url)
+        }
+        status => bail!("{} answered {}", url, status),
+    }
+}
+
+/// Downloads the input for `puzzle` into `inputs/YYYY/dayN/input.txt`,
+/// unless it is already there.
+pub fn fetch(config: &Config, puzzle: Puzzle) -> Result<Fetched> {
+    let path = input::default_path(puzzle);
+    if path.exists() {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .context("Failed to start the async runtime")?;
+    let input = runtime.block_on(download(config, puzzle))?;
+    if input.trim().is_empty() {
+        bail!("The server sent an empty input for {}", puzzle);
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    // Any file at `path` counts as cached, so it only appears once complete.
+    let partial = path.with_extension("txt.part");
+    fs::write(&partial, input).with_context(|| format!("Failed to write {}", partial.display()))?;
+    fs::rename(&partial, &path).with_context(|| format!("Failed to move {} to {}", partial.display(), path.display()))?;
+
+    Ok(Fetched::Downloaded(path))
+}
@@ -0,0 +1,419 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::bench::{Measurement, Phase};
+use crate::runner::Outcome;
+use crate::solution::{Puzzle, Task};
+use crate::table;
+
+/// Where every recorded run and benchmark is appended, one JSON entry per line.
+pub const PATH: &str = "history.jsonl";
+
+/// How many earlier entries of a task its baseline is the median of.
+const BASELINE_RUNS: usize = 5;
+
+/// Fewer earlier entries than this are too few to call anything a regression.
+const MIN_BASELINE_RUNS: usize = 3;
+
+/// Solve times below this vary too much between runs to compare.
+const MIN_BASELINE: Duration = Duration::from_micros(100);
+
+/// One timed task, as recorded in `history.jsonl`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    /// The checked out commit, with `-dirty` when there were local changes.
+    pub commit: Option<String>,
+    /// The host name, operating system and architecture.
+    pub machine: String,
+    /// `run` for a single measurement, `bench` for the statistics of many.
+    pub kind: String,
+    /// How many days `run` solved at the same time, which affects its times.
+    /// `bench` measures one task at a time and leaves it out.
+    pub jobs: Option<usize>,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub variant: Option<String>,
+    pub parse_ns: u64,
+    /// The measurement of `run`, or the median of `bench`.
+    pub solve_ns: u64,
+    pub min_ns: Option<u64>,
+    pub p95_ns: Option<u64>,
+    pub std_dev_ns: Option<u64>,
+}
+
+impl Entry {
+    fn task(&self) -> String {
+        match &self.variant {
+            Some(variant) => format!("part{}_{}", self.part, variant),
+            None => format!("part{}", self.part),
+        }
+    }
+
+    /// `run --jobs N` or `bench`.
+    fn method(&self) -> String {
+        match self.jobs {
+            Some(jobs) => format!("{} --jobs {}", self.kind, jobs),
+            None => self.kind.clone(),
+        }
+    }
+
+    /// Whether `other` timed the same task the same way on the same machine,
+    /// so their times can be compared.
+    fn comparable(&self, other: &Entry) -> bool {
+        (self.year, self.day, self.part, &self.variant, &self.kind, self.jobs, &self.machine)
+            == (other.year, other.day, other.part, &other.variant, &other.kind, other.jobs, &other.machine)
+    }
+}
+
+/// What every entry of one run has in common.
+struct Recording {
+    time: u64,
+    commit: Option<String>,
+    machine: String,
+}
+
+impl Recording {
+    fn current() -> Self {
+        Recording {
+            time: now(),
+            commit: commit(),
+            machine: machine(),
+        }
+    }
+
+    fn entry(&self, kind: &str, puzzle: Puzzle, task: Task, parse: Duration, solve: Duration) -> Entry {
+        Entry {
+            time: self.time,
+            commit: self.commit.clone(),
+            machine: self.machine.clone(),
+            kind: kind.to_string(),
+            jobs: None,
+            year: puzzle.year,
+            day: puzzle.day,
+            part: task.part.number(),
+            variant: task.variant.map(String::from),
+            parse_ns: parse.as_nanos() as u64,
+            solve_ns: solve.as_nanos() as u64,
+            min_ns: None,
+            p95_ns: None,
+            std_dev_ns: None,
+        }
+    }
+}
+
+/// Seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_secs())
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The checked out commit, with `-dirty` when tracked files have local changes.
+pub fn commit() -> Option<String> {
+    let hash = git(&["rev-parse", "--short", "HEAD"])?;
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => Some(format!("{}-dirty", hash)),
+        _ => Some(hash),
+    }
+}
+
+fn machine() -> String {
+    let host = fs::read_to_string("/etc/hostname")
+        .ok()
+        .map(|host| host.trim().to_string())
+        .filter(|host| !host.is_empty())
+        .or_else(|| {
+            let output = Command::new("hostname").output().ok()?;
+            Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        })
+        .unwrap_or_else(|| String::from("unknown"));
+    format!("{} ({}-{})", host, std::env::consts::OS, std::env::consts::ARCH)
+}
+
+/// An entry for each task `run` solved with `jobs` days at a time.
+pub fn from_outcomes(outcomes: &[Outcome], jobs: usize) -> Vec<Entry> {
+    let recording = Recording::current();
+    outcomes
+        .iter()
+        .filter(|outcome| outcome.answer.is_ok())
+        .map(|outcome| {
+            let (parse, solve) = (outcome.parse_time, outcome.solve_time);
+            let mut entry = recording.entry("run", outcome.puzzle, outcome.task, parse, solve);
+            entry.jobs = Some(jobs);
+            entry
+        })
+        .collect()
+}
+
+/// An entry for each task `bench` measured, with the median parse time of
+/// its day.
+pub fn from_measurements(measurements: &[Measurement]) -> Vec<Entry> {
+    let recording = Recording::current();
+    measurements
+        .iter()
+        .filter_map(|measurement| {
+            let (Phase::Solve(task), Ok(stats)) = (&measurement.phase, &measurement.stats) else {
+                return None;
+            };
+            let parse = measurements.iter().find_map(|other| match (&other.phase, &other.stats) {
+                (Phase::Parse, Ok(parse)) if other.puzzle == measurement.puzzle => Some(parse.median),
+                _ => None,
+            })?;
+
+            let mut entry = recording.entry("bench", measurement.puzzle, *task, parse, stats.median);
+            entry.min_ns = Some(stats.min.as_nanos() as u64);
+            entry.p95_ns = Some(stats.p95.as_nanos() as u64);
+            entry.std_dev_ns = Some(stats.std_dev.as_nanos() as u64);
+            Some(entry)
+        })
+        .collect()
+}
+
+pub fn load() -> Result<Vec<Entry>> {
+    let contents = match fs::read_to_string(PATH) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err).with_context(|| format!("Failed to read {}", PATH)),
+    };
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).with_context(|| format!("Invalid entry on line {} of {}", index + 1, PATH))
+        })
+        .collect()
+}
+
+pub fn append(entries: &[Entry]) -> Result<()> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(PATH)
+        .with_context(|| format!("Failed to open {}", PATH))?;
+    for entry in entries {
+        writeln!(file, "{}", serde_json::to_string(entry)?).with_context(|| format!("Failed to write {}", PATH))?;
+    }
+    Ok(())
+}
+
+/// A task that took longer than its baseline allows.
+pub struct Regression {
+    pub entry: Entry,
+    pub baseline: Duration,
+    /// How much slower than the baseline, in percent.
+    pub slowdown: f64,
+    /// How many earlier entries the baseline is the median of.
+    pub runs: usize,
+}
+
+fn median(mut values: Vec<u64>) -> u64 {
+    values.sort();
+    values[values.len() / 2]
+}
+
+/// The entries of `current` whose solve time is more than `threshold`
+/// percent above the median of the last few comparable entries in `history`.
+pub fn regressions(history: &[Entry], current: &[Entry], threshold: f64) -> Vec<Regression> {
+    current
+        .iter()
+        .filter_map(|entry| {
+            let earlier: Vec<u64> = history
+                .iter()
+                .rev()
+                .filter(|other| other.comparable(entry))
+                .take(BASELINE_RUNS)
+                .map(|other| other.solve_ns)
+                .collect();
+            if earlier.len() < MIN_BASELINE_RUNS {
+                return None;
+            }
+
+            let runs = earlier.len();
+            let baseline = Duration::from_nanos(median(earlier));
+            if baseline < MIN_BASELINE {
+                return None;
+            }
+
+            let slowdown = (entry.solve_ns as f64 / baseline.as_nanos() as f64 - 1.0) * 100.0;
+            (slowdown > threshold).then(|| Regression { entry: entry.clone(), baseline, slowdown, runs })
+        })
+        .collect()
+}
+
+pub fn print_regressions(regressions: &[Regression]) {
+    for regression in regressions {
+        let entry = &regression.entry;
+        eprintln!(
+            "REGRESSION: {} {} took {:.2?}, {:.0}% slower than the median {:.2?} of its last {} {} runs",
+            Puzzle::new(entry.year, entry.day),
+            entry.task(),
+            Duration::from_nanos(entry.solve_ns),
+            regression.slowdown,
+            regression.baseline,
+            regression.runs,
+            entry.method()
+        );
+    }
+}
+
+/// `YYYY-MM-DD HH:MM` in UTC, from Howard Hinnant's `civil_from_days`.
+pub fn format_time(time: u64) -> String {
+    let (days, seconds) = ((time / 86400) as i64, time % 86400);
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", year, month, day, seconds / 3600, seconds % 3600 / 60)
+}
+
+fn change(previous: Option<&Entry>, entry: &Entry) -> String {
+    match previous {
+        Some(previous) if previous.solve_ns > 0 => {
+            let change = (entry.solve_ns as f64 / previous.solve_ns as f64 - 1.0) * 100.0;
+            format!("{:+.0}%", change)
+        }
+        _ => String::from("-"),
+    }
+}
+
+/// Prints every recorded entry of `puzzle`, grouped by task and in the order
+/// they were recorded, with each solve time compared to the one before it.
+/// Returns how many entries there were.
+pub fn print_trend(history: &[Entry], puzzle: Puzzle) -> usize {
+    let mut entries: Vec<&Entry> = history
+        .iter()
+        .filter(|entry| entry.year == puzzle.year && entry.day == puzzle.day)
+        .collect();
+    entries.sort_by_key(|entry| {
+        (entry.part, entry.variant.clone(), entry.kind.clone(), entry.jobs, entry.machine.clone())
+    });
+
+    let rows: Vec<Vec<String>> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let previous = i.checked_sub(1).map(|i| entries[i]).filter(|previous| previous.comparable(entry));
+            vec![
+                entry.task(),
+                format_time(entry.time),
+                entry.commit.clone().unwrap_or_else(|| String::from("-")),
+                entry.machine.clone(),
+                entry.method(),
+                format!("{:.2?}", Duration::from_nanos(entry.parse_ns)),
+                format!("{:.2?}", Duration::from_nanos(entry.solve_ns)),
+                change(previous, entry),
+            ]
+        })
+        .collect();
+
+    if !rows.is_empty() {
+        table::print(&["Task", "Date (UTC)", "Commit", "Machine", "Kind", "Parse", "Solve", "Change"], &rows);
+    }
+    rows.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(day: u8, solve_ms: u64) -> Entry {
+        Entry {
+            time: 0,
+            commit: None,
+            machine: String::from("test (linux-x86_64)"),
+            kind: String::from("run"),
+            jobs: Some(1),
+            year: 2024,
+            day,
+            part: 1,
+            variant: None,
+            parse_ns: 0,
+            solve_ns: solve_ms * 1_000_000,
+            min_ns: None,
+            p95_ns: None,
+            std_dev_ns: None,
+        }
+    }
+
+    fn history(solve_ms: &[u64]) -> Vec<Entry> {
+        solve_ms.iter().map(|&ms| entry(1, ms)).collect()
+    }
+
+    #[test]
+    fn compares_with_the_median_of_the_last_runs() {
+        // The oldest entry falls out of the last five, the 100ms outlier does
+        // not move the median.
+        let history = history(&[1, 10, 10, 100, 11, 12]);
+        let found = regressions(&history, &[entry(1, 14)], 25.0);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].baseline, Duration::from_millis(11));
+        assert_eq!(found[0].runs, BASELINE_RUNS);
+        assert!((found[0].slowdown - 300.0 / 11.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn needs_enough_earlier_runs() {
+        let current = [entry(1, 100)];
+        let few = history(&[10; MIN_BASELINE_RUNS - 1]);
+        assert!(regressions(&few, &current, 25.0).is_empty());
+        let enough = history(&[10; MIN_BASELINE_RUNS]);
+        assert_eq!(regressions(&enough, &current, 25.0).len(), 1);
+    }
+
+    #[test]
+    fn only_flags_slowdowns_above_the_threshold() {
+        let history = history(&[8, 8, 8]);
+        assert!(regressions(&history, &[entry(1, 10)], 25.0).is_empty());
+        assert_eq!(regressions(&history, &[entry(1, 11)], 25.0).len(), 1);
+        assert!(regressions(&history, &[entry(1, 11)], 50.0).is_empty());
+    }
+
+    #[test]
+    fn ignores_fast_tasks() {
+        let history: Vec<Entry> = (0..3).map(|_| Entry { solve_ns: 50_000, ..entry(1, 0) }).collect();
+        assert!(regressions(&history, &[entry(1, 1)], 25.0).is_empty());
+    }
+
+    #[test]
+    fn only_compares_the_same_task_and_method() {
+        let mut history = history(&[10, 10, 10]);
+        history.extend((0..3).map(|_| entry(2, 10)));
+        history.extend((0..3).map(|_| Entry { jobs: Some(8), ..entry(1, 50) }));
+
+        assert!(regressions(&history, &[entry(3, 100)], 25.0).is_empty());
+        assert!(regressions(&history, &[Entry { jobs: Some(8), ..entry(1, 55) }], 25.0).is_empty());
+        assert_eq!(regressions(&history, &[entry(1, 50)], 25.0).len(), 1);
+    }
+
+    #[test]
+    fn formats_times_in_utc() {
+        assert_eq!(format_time(0), "1970-01-01 00:00");
+        assert_eq!(format_time(951782400), "2000-02-29 00:00");
+        assert_eq!(format_time(1709251199), "2024-02-29 23:59");
+        assert_eq!(format_time(1733029200), "2024-12-01 05:00");
+        assert_eq!(format_time(4107542399), "2100-02-28 23:59");
+    }
+}
@@ -0,0 +1,112 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use anyhow::Result;
+
+use crate::error::Error;
+use crate::examples::{self, Example};
+use crate::solution::{Params, Puzzle};
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `inputs/YYYY/dayN/input.txt`
+    Default,
+    File(PathBuf),
+    Stdin,
+    /// `inputs/YYYY/dayN/<name>.txt`, run with the parameters listed for it
+    /// in `examples.toml` next to it.
+    Example(String),
+}
+
+impl InputSource {
+    /// Interprets the value of `--input`, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Where the input comes from, a path or `stdin`, for pointing at it in errors.
+    pub fn describe(&self, puzzle: Puzzle) -> String {
+        match self {
+            InputSource::Default => default_path(puzzle).display().to_string(),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => String::from("stdin"),
+            InputSource::Example(name) => examples::path(puzzle, name).display().to_string(),
+        }
+    }
+
+    pub fn read(&self, puzzle: Puzzle) -> Result<String, Error> {
+        let path = match self {
+            InputSource::Default => default_path(puzzle),
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                return match io::stdin().read_to_string(&mut input) {
+                    Ok(_) => Ok(input),
+                    Err(source) => Err(Error::Io { path: PathBuf::from("stdin"), source }),
+                };
+            }
+            InputSource::Example(name) => examples::path(puzzle, name),
+        };
+        fs::read_to_string(&path).map_err(|source| Error::Io { path, source })
+    }
+
+    /// The parameters the input is solved with. Only examples have their own,
+    /// everything else uses the solution's defaults.
+    pub fn params(&self, puzzle: Puzzle) -> Result<Params> {
+        match self {
+            InputSource::Example(name) => Ok(Example::load(puzzle, name)?.params),
+            _ => Ok(Params::default()),
+        }
+    }
+}
+
+/// The input as every parser sees it: without a byte order mark, with `\n`
+/// line endings, no trailing whitespace on any line and no trailing blank
+/// lines or final newline.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+    lines.join("\n").trim_end_matches('\n').to_string()
+}
+
+/// Where everything about one puzzle is kept: `inputs/YYYY/dayN`.
+pub fn dir(puzzle: Puzzle) -> PathBuf {
+    PathBuf::from(format!("inputs/{}/day{}", puzzle.year, puzzle.day))
+}
+
+pub fn default_path(puzzle: Puzzle) -> PathBuf {
+    dir(puzzle).join("input.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_a_byte_order_mark() {
+        assert_eq!(normalize("\u{feff}1 2\n3 4\n"), "1 2\n3 4");
+    }
+
+    #[test]
+    fn converts_crlf_line_endings() {
+        assert_eq!(normalize("#.#\r\n.#.\r\n\r\n<>\r\n"), "#.#\n.#.\n\n<>");
+    }
+
+    #[test]
+    fn trims_trailing_whitespace_on_each_line() {
+        assert_eq!(normalize("a \t\nb  \n  c"), "a\nb\n  c");
+    }
+
+    #[test]
+    fn drops_trailing_blank_lines_but_keeps_inner_ones() {
+        assert_eq!(normalize("a\n\nb\n\n\n  \n"), "a\n\nb");
+        assert_eq!(normalize("\n\n"), "");
+        assert_eq!(normalize(""), "");
+    }
+}
@@ -0,0 +1,65 @@
+use crate::answers::Answers;
+use crate::examples;
+use crate::input;
+use crate::registry::{DynSolution, Registry};
+use crate::runner::Selection;
+use crate::solution::Task;
+use crate::table;
+
+fn params(solution: &dyn DynSolution) -> String {
+    let names: Vec<String> = solution
+        .params()
+        .iter()
+        .map(|param| match param.part {
+            Some(part) => format!("{}.{}", part, param.name),
+            None => param.name.to_string(),
+        })
+        .collect();
+    if names.is_empty() {
+        String::from("-")
+    } else {
+        names.join(", ")
+    }
+}
+
+/// `yes` when every task has a stored answer, `answered/total` when only some do.
+fn answers(solution: &dyn DynSolution, tasks: &[Task]) -> String {
+    let answers = match Answers::load(solution.puzzle()) {
+        Ok(answers) => answers,
+        Err(_) => return String::from("invalid"),
+    };
+
+    match tasks.iter().filter(|&&task| answers.get(task).is_some()).count() {
+        0 => String::from("no"),
+        answered if answered == tasks.len() => String::from("yes"),
+        answered => format!("{}/{}", answered, tasks.len()),
+    }
+}
+
+/// Prints what is registered for every selected puzzle and which of its
+/// files exist, returning how many puzzles were listed.
+pub fn print(registry: &Registry, selection: Selection) -> usize {
+    let rows: Vec<Vec<String>> = registry
+        .iter()
+        .filter(|solution| selection.contains(solution.puzzle()))
+        .map(|solution| {
+            let puzzle = solution.puzzle();
+            let tasks = solution.tasks();
+            let examples = examples::names(puzzle);
+            vec![
+                puzzle.year.to_string(),
+                puzzle.day.to_string(),
+                tasks.iter().map(Task::to_string).collect::<Vec<_>>().join(", "),
+                params(solution),
+                if examples.is_empty() { String::from("-") } else { examples.join(", ") },
+                if input::default_path(puzzle).exists() { "yes" } else { "no" }.to_string(),
+                answers(solution, &tasks),
+            ]
+        })
+        .collect();
+
+    if !rows.is_empty() {
+        table::print(&["Year", "Day", "Tasks", "Params", "Examples", "Input", "Answers"], &rows);
+    }
+    rows.len()
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+use crate::output;
+
+/// How much diagnostic output to show, from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Debug,
+    Trace,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The most verbose level shown: warnings by default, `--quiet` for errors
+/// only, and `-v` or `-vv` for debug or trace output.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+/// The days given with `--log`. Diagnostics of other days are dropped.
+static DAYS: OnceLock<Vec<u8>> = OnceLock::new();
+
+pub fn init(level: Level, days: Vec<u8>) {
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+    if !days.is_empty() {
+        DAYS.set(days).expect("Logging is initialised once");
+    }
+}
+
+/// The day a module belongs to, from a path like `advent_of_code::years::y2024::day15::utils`.
+fn day_of(module: &str) -> Option<u8> {
+    module.split("::").find_map(|segment| segment.strip_prefix("day")?.parse().ok())
+}
+
+/// Whether a message at `level` from `module` would be shown. The macros check
+/// this before formatting, so filtered messages cost next to nothing.
+pub fn enabled(level: Level, module: &str) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+
+    match (DAYS.get(), day_of(module)) {
+        (Some(days), Some(day)) => days.contains(&day),
+        _ => true,
+    }
+}
+
+pub fn write(level: Level, module: &str, args: fmt::Arguments) {
+    let source = module.strip_prefix("advent_of_code::").unwrap_or(module);
+    output::write(format_args!("[{} {}] {}\n", level, source, args));
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Error, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Warn, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Debug, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Trace, $($arg)*) };
+}
@@ -1,75 +1,575 @@
 use std::env;
+use std::process::ExitCode;
 use std::time::Instant;
-mod days;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args: Vec<String> = env::args().collect();
+use cli::{Command, Format, Options, YearChoice};
+use config::Config;
+use error::Error;
+use fetch::Fetched;
+use input::InputSource;
+use registry::{parse_day, Registry};
+use runner::{DaySelector, Selection};
+use solution::{Params, Puzzle, Task};
+use submit::Reply;
 
-    if args.len() != 3 {
-        eprintln!("Usage: cargo run <day> <part>");
-        return Ok(());
+mod answers;
+mod bench;
+mod cli;
+mod completions;
+mod config;
+mod crosscheck;
+mod error;
+mod examples;
+mod explain;
+mod fetch;
+mod history;
+mod input;
+mod list;
+mod log;
+mod memory;
+mod output;
+mod profile;
+mod registry;
+mod repl;
+mod report;
+mod runner;
+mod scaffold;
+mod shape;
+mod solution;
+mod submit;
+mod table;
+mod verify;
+mod watch;
+mod years;
+
+/// The year `--year` picks, or `None` for all of them.
+fn year(registry: &Registry, choice: YearChoice) -> Option<u16> {
+    match choice {
+        YearChoice::Latest => Some(registry.latest_year().expect("At least one year has solutions")),
+        YearChoice::Year(year) => Some(year),
+        YearChoice::All => None,
+    }
+}
+
+fn describe(day: u8, task: Task) -> String {
+    match task.variant {
+        Some(variant) => format!("Day {} Part {} ({})", day, task.part.number(), variant),
+        None => format!("Day {} Part {}", day, task.part.number()),
+    }
+}
+
+/// Resolves a day and task as typed on the command line, explaining what
+/// exists when they do not.
+fn find_task(registry: &Registry, year: u16, day: &str, task: &str) -> Result<(Puzzle, Task), String> {
+    let puzzle = parse_day(day)
+        .map(|day| Puzzle::new(year, day))
+        .ok_or_else(|| format!("Invalid day: {}, expected e.g. day5", day))?;
+    let solution = registry
+        .get(puzzle)
+        .ok_or_else(|| format!("{} has no registered solution, see list for the registered days", puzzle))?;
+
+    match registry.find_task(puzzle, task) {
+        Some(task) => Ok((puzzle, task)),
+        None => {
+            let tasks: Vec<String> = solution.tasks().iter().map(Task::to_string).collect();
+            Err(format!("{} has no task {}, expected one of: {}", puzzle, task, tasks.join(", ")))
+        }
+    }
+}
+
+fn solve(registry: &Registry, year: u16, day: &str, task: &str, options: &Options) -> ExitCode {
+    let (puzzle, task) = match find_task(registry, year, day, task) {
+        Ok(found) => found,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solution = registry.get(puzzle).expect("Task was found for a registered day");
+
+    let selection = Selection { year: Some(year), days: DaySelector::Day(puzzle.day) };
+    if let Err(err) = runner::check_overrides(registry, selection, &options.params) {
+        eprintln!("{}", err);
+        return ExitCode::FAILURE;
+    }
+
+    let outcome = runner::with_silent_panics(|| runner::run_day(solution, &options.input, &options.params, &[task]))
+        .pop()
+        .expect("One outcome per task");
+
+    if options.format == Format::Json {
+        runner::print_json(std::slice::from_ref(&outcome));
+        return if outcome.answer.is_ok() { ExitCode::SUCCESS } else { ExitCode::FAILURE };
+    }
+
+    let duration = outcome.parse_time + outcome.solve_time;
+    eprint!("{}", outcome.log);
+
+    match outcome.answer {
+        Ok(answer) => {
+            println!("{}: {}", describe(puzzle.day, task), answer);
+            println!("Execution time: {:.2?}", duration);
+            if let Some(usage) = outcome.memory {
+                println!(
+                    "Peak heap: {}, {} allocations of {} in total",
+                    memory::format_bytes(usage.peak),
+                    usage.allocations,
+                    memory::format_bytes(usage.allocated)
+                );
+            }
+            if options.profile {
+                println!();
+                print_profile();
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            match err.downcast_ref::<Error>() {
+                Some(Error::Parse(parse)) => eprintln!("Failed to parse the input: {}", parse.diagnostic()),
+                _ => eprintln!("Error occurred: {:#} (Execution time: {:.2?})", err, duration),
+            }
+            ExitCode::FAILURE
+        }
     }
+}
 
-    let day = &args[1];
-    let part = &args[2];
+fn print_profile() {
+    if let Err(err) = profile::report() {
+        eprintln!("Error occurred: {:#}", err);
+    }
+}
+
+/// Whether the timings of `run` and `bench` are comparable with the ones in
+/// the history: solving the default input with the default parameters,
+/// without the overhead of counting allocations or recording spans.
+fn records_history(options: &Options) -> bool {
+    options.input == InputSource::Default && options.params == Params::default() && !options.memory && !options.profile
+}
+
+/// Warns about tasks that got slower than their recent baseline, then adds
+/// `entries` to the history.
+fn record_history(entries: &[history::Entry], options: &Options) {
+    let recorded = history::load().and_then(|history| {
+        history::print_regressions(&history::regressions(&history, entries, options.regression));
+        history::append(entries)
+    });
+    if let Err(err) = recorded {
+        eprintln!("Error occurred: {:#}", err);
+    }
+}
+
+fn run(registry: &Registry, selection: Selection, task: Option<&str>, options: &Options) -> ExitCode {
+    if let Err(err) = runner::check_overrides(registry, selection, &options.params) {
+        eprintln!("{}", err);
+        return ExitCode::FAILURE;
+    }
 
     let start = Instant::now();
+    let outcomes = runner::run_all(registry, selection, task, &options.input, &options.params, options.jobs);
+    if outcomes.is_empty() {
+        eprintln!("No registered solutions match the selection");
+        return ExitCode::FAILURE;
+    }
+
+    match options.format {
+        Format::Table => {
+            runner::print_logs(&outcomes);
+            runner::print_table(&outcomes);
+            println!("Finished in {:.2?} with --jobs {}", start.elapsed(), options.jobs);
+            if options.memory {
+                println!();
+                runner::print_memory(&outcomes);
+            }
+            if options.profile {
+                println!();
+                print_profile();
+            }
+        }
+        Format::Json => runner::print_json(&outcomes),
+    }
+
+    if records_history(options) {
+        record_history(&history::from_outcomes(&outcomes, options.jobs), options);
+    }
+
+    if outcomes.iter().all(|outcome| outcome.answer.is_ok()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn verify(registry: &Registry, selection: Selection, task: Option<&str>, options: &Options) -> ExitCode {
+    let source = &options.input;
+    let outcomes = runner::run_all(registry, selection, task, source, &Params::default(), options.jobs);
+    if outcomes.is_empty() {
+        eprintln!("No registered solutions match the selection");
+        return ExitCode::FAILURE;
+    }
+
+    let verdicts = verify::check(&outcomes, source);
+    verify::print_report(&outcomes, &verdicts);
+
+    if verdicts.iter().any(|verdict| verdict.is_failure()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench(registry: &Registry, selection: Selection, task: Option<&str>, options: &Options) -> ExitCode {
+    if let Err(err) = runner::check_overrides(registry, selection, &options.params) {
+        eprintln!("{}", err);
+        return ExitCode::FAILURE;
+    }
+
+    let measurements = bench::bench_all(registry, selection, task, &options.input, &options.params, &options.bench);
+    if measurements.is_empty() {
+        eprintln!("No registered solutions match the selection");
+        return ExitCode::FAILURE;
+    }
+
+    bench::print_table(&measurements, &options.bench);
+
+    if records_history(options) {
+        record_history(&history::from_measurements(&measurements), options);
+    }
+
+    if measurements.iter().all(|measurement| measurement.stats.is_ok()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn crosscheck(registry: &Registry, selection: Selection, options: &Options) -> ExitCode {
+    if let Err(err) = runner::check_overrides(registry, selection, &options.params) {
+        eprintln!("{}", err);
+        return ExitCode::FAILURE;
+    }
+
+    let checks = crosscheck::crosscheck(registry, selection, &options.input, &options.params, options.jobs);
+    if checks.is_empty() {
+        eprintln!("No selected day has variants to cross-check");
+        return ExitCode::FAILURE;
+    }
+
+    crosscheck::print_report(&checks);
+
+    for check in &checks {
+        if let crosscheck::Verdict::Differs { reference, actual } = &check.verdict {
+            let (puzzle, task) = (check.outcome.puzzle, check.outcome.task);
+            eprintln!("MISMATCH: {} {} answered {}, but {} answered {}", puzzle, task, actual, task.part, reference);
+        }
+    }
+
+    if checks.iter().any(|check| check.verdict.is_failure()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn watch(registry: &Registry, year: u16, day: &str, task: Option<&str>, options: &Options) -> ExitCode {
+    let Some(puzzle) = parse_day(day).map(|day| Puzzle::new(year, day)) else {
+        eprintln!("Invalid day: {}", day);
+        return ExitCode::FAILURE;
+    };
+    let Some(solution) = registry.get(puzzle) else {
+        eprintln!("{} has no registered solution", puzzle);
+        return ExitCode::FAILURE;
+    };
+
+    let tasks = match task {
+        Some(task) => match find_task(registry, year, day, task) {
+            Ok((_, task)) => vec![task],
+            Err(err) => {
+                eprintln!("{}", err);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => solution.tasks(),
+    };
+
+    let selection = Selection { year: Some(year), days: DaySelector::Day(puzzle.day) };
+    if let Err(err) = runner::check_overrides(registry, selection, &options.params) {
+        eprintln!("{}", err);
+        return ExitCode::FAILURE;
+    }
+
+    watch::watch(solution, &options.input, &options.params, &tasks)
+}
+
+fn repl(registry: &Registry, year: u16, day: &str, options: &Options) -> ExitCode {
+    let Some(puzzle) = parse_day(day).map(|day| Puzzle::new(year, day)) else {
+        eprintln!("Invalid day: {}", day);
+        return ExitCode::FAILURE;
+    };
+    let Some(solution) = registry.get(puzzle) else {
+        eprintln!("{} has no registered solution", puzzle);
+        return ExitCode::FAILURE;
+    };
+
+    let selection = Selection { year: Some(year), days: DaySelector::Day(puzzle.day) };
+    if let Err(err) = runner::check_overrides(registry, selection, &options.params) {
+        eprintln!("{}", err);
+        return ExitCode::FAILURE;
+    }
+
+    match repl::repl(solution, &options.input, &options.params) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error occurred: {:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn help(registry: &Registry, puzzle: Option<Puzzle>) -> ExitCode {
+    let Some(puzzle) = puzzle else {
+        println!("{}", cli::USAGE);
+        return ExitCode::SUCCESS;
+    };
+
+    let Some(solution) = registry.get(puzzle) else {
+        eprintln!("{} has no registered solution", puzzle);
+        return ExitCode::FAILURE;
+    };
+
+    let tasks: Vec<String> = solution.tasks().iter().map(Task::to_string).collect();
+    println!("{}", puzzle);
+    println!();
+    println!("Tasks: {}", tasks.join(", "));
+    println!();
+
+    let params = solution.params();
+    if params.is_empty() {
+        println!("No parameters");
+        return ExitCode::SUCCESS;
+    }
+
+    let rows: Vec<Vec<String>> = params
+        .iter()
+        .map(|param| {
+            vec![
+                param.name.to_string(),
+                param.part.map_or(String::from("both"), |part| part.to_string()),
+                param.default.to_string(),
+                param.description.to_string(),
+            ]
+        })
+        .collect();
+    table::print(&["Parameter", "Part", "Default", "Description"], &rows);
+    println!();
+    println!("Override with --param name=value, or --param part2.name=value for one part only");
+
+    ExitCode::SUCCESS
+}
+
+fn report(registry: &Registry, year: u16, options: &Options) -> ExitCode {
+    let selection = Selection { year: Some(year), days: DaySelector::All };
+    let outcomes = runner::run_all(registry, selection, None, &InputSource::Default, &Params::default(), options.jobs);
+    if outcomes.is_empty() {
+        eprintln!("{} has no registered solutions", year);
+        return ExitCode::FAILURE;
+    }
+
+    let verdicts = verify::check(&outcomes, &InputSource::Default);
+    let report = report::Report::new(year, &outcomes, &verdicts, &options.report);
+
+    match report.write(&options.report) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Error occurred: {:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn explain(registry: &Registry, puzzle: Puzzle, dry_run: bool) -> ExitCode {
+    if registry.get(puzzle).is_none() {
+        eprintln!("{} has no registered solution", puzzle);
+        return ExitCode::FAILURE;
+    }
+
+    let explained = Config::load().and_then(|config| {
+        let request = explain::request(&config, puzzle)?;
+        if dry_run {
+            explain::describe(&config, &request)
+        } else {
+            explain::explain(&config, &request)
+        }
+    });
+
+    match explained {
+        Ok(text) => {
+            println!("{}", text);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Error occurred: {:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn show_history(puzzle: Puzzle) -> ExitCode {
+    let history = match history::load() {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("Error occurred: {:#}", err);
+            return ExitCode::FAILURE;
+        }
+    };
 
-    let result = match (day.as_str(), part.as_str()) {
-        ("day1", "part1") => days::day1::part1::solve(),
-        ("day1", "part2") => days::day1::part2::solve(),
-        ("day2", "part1") => days::day2::part1::solve(),
-        ("day2", "part1_optimized") => days::day2::part1_optimized::solve(),
-        ("day2", "part2") => days::day2::part2::solve(),
-        ("day2", "part2_optimized") => days::day2::part2_optimized::solve(),
-        ("day3", "part1") => days::day3::part1::solve(),
-        ("day3", "part2") => days::day3::part2::solve(),
-        ("day4", "part1") => days::day4::part1::solve(),
-        ("day4", "part2") => days::day4::part2::solve(),
-        ("day5", "part1") => days::day5::part1::solve(),
-        ("day5", "part2") => days::day5::part2::solve(),
-        ("day6", "part1") => days::day6::part1::solve(),
-        ("day6", "part2") => days::day6::part2::solve(),
-        ("day7", "part1") => days::day7::part1::solve(),
-        ("day7", "part2") => days::day7::part2::solve(),
-        ("day8", "part1") => days::day8::part1::solve(),
-        ("day8", "part2") => days::day8::part2::solve(),
-        ("day9", "part1") => days::day9::part1::solve(),
-        ("day9", "part2") => days::day9::part2::solve(),
-        ("day10", "part1") => days::day10::part1::solve(),
-        ("day10", "part2") => days::day10::part2::solve(),
-        ("day11", "part1") => days::day11::part1::solve(),
-        ("day11", "part2") => days::day11::part2::solve(),
-        ("day12", "part1") => days::day12::part1::solve(),
-        ("day12", "part2") => days::day12::part2::solve(),
-        ("day13", "part1") => days::day13::part1::solve(),
-        ("day13", "part2") => days::day13::part2::solve(),
-        ("day14", "part1") => days::day14::part1::solve(),
-        ("day14", "part2") => days::day14::part2::solve(),
-        ("day15", "part1") => days::day15::part1::solve(),
-        ("day15", "part2") => days::day15::part2::solve(),
-        ("day16", "part1") => days::day16::part1::solve(),
-        ("day16", "part2") => days::day16::part2::solve(),
-        ("day17", "part1") => days::day17::part1::solve(),
-        ("day17", "part2") => days::day17::part2::solve(),
-        ("day18", "part1") => days::day18::part1::solve(),
-        ("day18", "part2") => days::day18::part2::solve(),
-        ("day19", "part1") => days::day19::part1::solve(),
-        ("day19", "part2") => days::day19::part2::solve(),
-        ("day20", "part1") => days::day20::part1::solve(),
-        ("day20", "part2") => days::day20::part2::solve(),
-        _ => {
-            eprintln!("Invalid day or part. Usage: cargo run <day> <part>");
-            Ok(())
+    if history::print_trend(&history, puzzle) == 0 {
+        eprintln!("{} has no recorded runs in {}", puzzle, history::PATH);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn list(registry: &Registry, selection: Selection) -> ExitCode {
+    if list::print(registry, selection) == 0 {
+        eprintln!("No registered solutions match the selection");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn new_day(registry: &Registry, puzzle: Puzzle) -> ExitCode {
+    if registry.get(puzzle).is_some() {
+        eprintln!("{} already has a registered solution", puzzle);
+        return ExitCode::FAILURE;
+    }
+
+    match scaffold::new_day(puzzle) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Error occurred: {:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch(puzzle: Puzzle) -> ExitCode {
+    let fetched = Config::load().and_then(|config| fetch::fetch(&config, puzzle));
+
+    match fetched {
+        Ok(Fetched::Downloaded(path)) => {
+            println!("Downloaded {} to {}", puzzle, path.display());
+            ExitCode::SUCCESS
+        }
+        Ok(Fetched::Cached(path)) => {
+            println!("{} is already at {}, not downloading it again", puzzle, path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Error occurred: {:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit(registry: &Registry, year: u16, day: &str, task: &str) -> ExitCode {
+    let (puzzle, task) = match find_task(registry, year, day, task) {
+        Ok(found) => found,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solution = registry.get(puzzle).expect("Task was found for a registered day");
+    let outcome = runner::run_day(solution, &InputSource::Default, &Params::default(), &[task])
+        .pop()
+        .expect("One outcome per task");
+
+    let answer = match outcome.answer {
+        Ok(answer) => answer.to_string(),
+        Err(err) => {
+            eprintln!("Error occurred: {:#}", err);
+            return ExitCode::FAILURE;
         }
     };
+    println!("{}: {}", describe(puzzle.day, task), answer);
 
-    let duration = start.elapsed();
+    match Config::load().and_then(|config| submit::submit(&config, puzzle, task.part, &answer)) {
+        Ok(reply) => {
+            println!("{}", reply);
+            if reply == Reply::Correct {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("Error occurred: {:#}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    match &result {
-        Ok(_) => println!("Execution time: {:.2?}", duration),
-        Err(err) => eprintln!("Error occurred: {} (Execution time: {:.2?})", err, duration),
+    let (command, options) = match cli::parse(&args) {
+        Ok(parsed) => parsed,
+        Err(usage) => {
+            eprintln!("{}", usage);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if options.memory {
+        memory::enable();
+    }
+    if options.profile {
+        profile::enable();
     }
+    log::init(options.log_level, options.log_days.clone());
 
-    result
-}
\ No newline at end of file
+    let registry = Registry::new();
+    let year = year(&registry, options.year);
+    // `cli::parse` only accepts `--year all` for commands that take a selection.
+    let single_year = || year.expect("A single year was chosen");
+
+    match command {
+        Command::Solve { day, task } => solve(&registry, single_year(), &day, &task, &options),
+        Command::Run { selector, task } => {
+            run(&registry, Selection { year, days: selector }, task.as_deref(), &options)
+        }
+        Command::Verify { selector, task } => {
+            verify(&registry, Selection { year, days: selector }, task.as_deref(), &options)
+        }
+        Command::Bench { selector, task } => {
+            bench(&registry, Selection { year, days: selector }, task.as_deref(), &options)
+        }
+        Command::Crosscheck { selector } => crosscheck(&registry, Selection { year, days: selector }, &options),
+        Command::Watch { day, task } => watch(&registry, single_year(), &day, task.as_deref(), &options),
+        Command::Repl { day } => repl(&registry, single_year(), &day, &options),
+        Command::New { day } => new_day(&registry, Puzzle::new(single_year(), day)),
+        Command::Fetch { day } => fetch(Puzzle::new(single_year(), day)),
+        Command::Submit { day, task } => submit(&registry, single_year(), &day, &task),
+        Command::List { selector } => list(&registry, Selection { year, days: selector }),
+        Command::History { day } => show_history(Puzzle::new(single_year(), day)),
+        Command::Report => report(&registry, single_year(), &options),
+        Command::Explain { day } => explain(&registry, Puzzle::new(single_year(), day), options.dry_run),
+        Command::Completions { shell } => {
+            print!("{}", completions::script(&registry, shell));
+            ExitCode::SUCCESS
+        }
+        Command::Help { day } => help(&registry, day.map(|day| Puzzle::new(single_year(), day))),
+    }
+}
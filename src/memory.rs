@@ -0,0 +1,126 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether allocations are being counted, set once by `--memory`. Until then
+/// the allocator only pays for loading this flag.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+// Counters are per thread, so days running side by side each see only their
+// own allocations. Const-initialised cells never allocate themselves.
+thread_local! {
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static ALLOCATED: Cell<u64> = const { Cell::new(0) };
+}
+
+/// The system allocator, counting heap usage of the calling thread while enabled.
+struct Counting;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn record(allocated: usize, freed: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    // `try_with` because allocations still happen while thread locals are torn down.
+    let _ = CURRENT.try_with(|current| {
+        current.set(current.get() + allocated as isize - freed as isize);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(current.get())));
+    });
+    if allocated > 0 {
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        let _ = ALLOCATED.try_with(|bytes| bytes.set(bytes.get() + allocated as u64));
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Heap usage of one measured piece of work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    /// The most memory held at once beyond what was held when measuring began.
+    pub peak: u64,
+    pub allocations: u64,
+    /// Bytes requested over all allocations, including ones freed again.
+    pub allocated: u64,
+}
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Runs `f` on this thread and reports its heap usage, or `None` unless
+/// counting was enabled.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+
+    CURRENT.set(0);
+    PEAK.set(0);
+    ALLOCATIONS.set(0);
+    ALLOCATED.set(0);
+
+    let result = f();
+
+    let usage = Usage {
+        peak: PEAK.get() as u64,
+        allocations: ALLOCATIONS.get(),
+        allocated: ALLOCATED.get(),
+    };
+    (result, Some(usage))
+}
+
+/// Formats a byte count with a binary unit, e.g. `1.50 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
@@ -0,0 +1,23 @@
+use std::cell::RefCell;
+use std::fmt::{self, Write};
+
+thread_local! {
+    /// Where log lines go on this thread: a buffer while capturing, stderr otherwise.
+    static CAPTURED: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, collecting everything logged on this thread instead of writing
+/// it to stderr, so days running side by side do not interleave their lines.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, String) {
+    let previous = CAPTURED.replace(Some(String::new()));
+    let result = f();
+    let captured = CAPTURED.replace(previous).unwrap_or_default();
+    (result, captured)
+}
+
+pub fn write(args: fmt::Arguments) {
+    CAPTURED.with_borrow_mut(|captured| match captured {
+        Some(buffer) => buffer.write_fmt(args).expect("Writing to a String cannot fail"),
+        None => eprint!("{}", args),
+    });
+}
@@ -0,0 +1,172 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+
+use crate::table;
+
+/// Whether spans are recorded, set once by `--profile`. Until then a span
+/// costs a load of this flag.
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Where `--profile` writes the folded stacks.
+pub const FOLDED_PATH: &str = "profile.folded";
+
+/// Time spent in one span name over all its calls.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Totals {
+    pub calls: u64,
+    /// Including nested spans, counted once for recursive calls.
+    pub total: Duration,
+    /// Excluding nested spans.
+    pub own: Duration,
+}
+
+#[derive(Default)]
+struct Recorded {
+    /// Own time per stack, keyed by the span names from the root joined with `;`.
+    folded: BTreeMap<String, Duration>,
+    spans: BTreeMap<String, Totals>,
+}
+
+impl Recorded {
+    fn merge(&mut self, other: Recorded) {
+        for (stack, own) in other.folded {
+            *self.folded.entry(stack).or_default() += own;
+        }
+        for (name, totals) in other.spans {
+            let entry = self.spans.entry(name).or_default();
+            entry.calls += totals.calls;
+            entry.total += totals.total;
+            entry.own += totals.own;
+        }
+    }
+}
+
+struct Frame {
+    name: String,
+    start: Instant,
+    /// Time spent in spans nested directly inside this one.
+    children: Duration,
+}
+
+thread_local! {
+    // Spans are recorded per thread and merged by `collect`, so hot spans
+    // such as day 6's `traverse` do not contend on a lock.
+    static STACK: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+    static RECORDED: RefCell<Recorded> = RefCell::new(Recorded::default());
+}
+
+static COLLECTED: Mutex<Option<Recorded>> = Mutex::new(None);
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Records the time until the returned guard is dropped under `name`, nested
+/// inside whatever span is open on this thread.
+pub fn span(name: &str) -> Span {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return Span { active: false };
+    }
+
+    STACK.with_borrow_mut(|stack| {
+        stack.push(Frame { name: name.to_string(), start: Instant::now(), children: Duration::ZERO })
+    });
+    Span { active: true }
+}
+
+pub struct Span {
+    active: bool,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if !self.active {
+            return;
+        }
+
+        STACK.with_borrow_mut(|stack| {
+            let frame = stack.pop().expect("Spans close in the order they opened");
+            let elapsed = frame.start.elapsed();
+            let own = elapsed.saturating_sub(frame.children);
+            if let Some(parent) = stack.last_mut() {
+                parent.children += elapsed;
+            }
+
+            let recursive = stack.iter().any(|outer| outer.name == frame.name);
+            let mut path: Vec<&str> = stack.iter().map(|outer| outer.name.as_str()).collect();
+            path.push(&frame.name);
+
+            RECORDED.with_borrow_mut(|recorded| {
+                *recorded.folded.entry(path.join(";")).or_default() += own;
+
+                let totals = recorded.spans.entry(frame.name.clone()).or_default();
+                totals.calls += 1;
+                totals.own += own;
+                if !recursive {
+                    totals.total += elapsed;
+                }
+            });
+        });
+    }
+}
+
+/// Hands the spans recorded on this thread over to `write_folded` and
+/// `print_breakdown`. Threads call this when they finish a day.
+pub fn collect() {
+    let recorded = RECORDED.take();
+    let mut collected = COLLECTED.lock().expect("Profile lock is never poisoned");
+    collected.get_or_insert_with(Recorded::default).merge(recorded);
+}
+
+fn collected() -> Recorded {
+    COLLECTED.lock().expect("Profile lock is never poisoned").take().unwrap_or_default()
+}
+
+/// Writes collected stacks in the folded format flamegraph tools read: one
+/// `root;child;grandchild microseconds` line per stack, counting own time.
+fn write_folded(recorded: &Recorded, path: &Path) -> Result<()> {
+    let lines: String = recorded
+        .folded
+        .iter()
+        .map(|(stack, own)| (stack, own.as_micros()))
+        .filter(|&(_, micros)| micros > 0)
+        .map(|(stack, micros)| format!("{} {}\n", stack.replace(' ', "_"), micros))
+        .collect();
+    fs::write(path, lines).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn print_breakdown(recorded: &Recorded) {
+    let mut spans: Vec<(&String, &Totals)> = recorded.spans.iter().collect();
+    spans.sort_by_key(|&(name, totals)| (std::cmp::Reverse(totals.total), name));
+
+    let rows: Vec<Vec<String>> = spans
+        .iter()
+        .map(|(name, totals)| {
+            vec![
+                name.to_string(),
+                totals.calls.to_string(),
+                format!("{:.2?}", totals.total),
+                format!("{:.2?}", totals.own),
+            ]
+        })
+        .collect();
+    table::print(&["Span", "Calls", "Total", "Own"], &rows);
+}
+
+/// Prints the per-span breakdown of everything collected and writes the
+/// folded stacks to `FOLDED_PATH`.
+pub fn report() -> Result<()> {
+    let recorded = collected();
+    print_breakdown(&recorded);
+    write_folded(&recorded, Path::new(FOLDED_PATH))?;
+    println!();
+    println!("Wrote folded stacks to {}, e.g. for inferno-flamegraph or flamegraph.pl", FOLDED_PATH);
+    Ok(())
+}
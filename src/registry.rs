@@ -0,0 +1,199 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+
+use crate::input;
+use crate::solution::{Answer, Param, Params, Part, Puzzle, Simulation, Solution, Task};
+use crate::years;
+
+/// Object-safe view of a `Solution`, so days with different input types can
+/// live in the same registry.
+pub trait DynSolution: Send + Sync {
+    fn puzzle(&self) -> Puzzle;
+
+    fn tasks(&self) -> Vec<Task>;
+
+    fn params(&self) -> Vec<Param>;
+
+    /// Normalizes and validates the input before parsing it.
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    fn solve(&self, input: &dyn Any, task: Task, params: &Params) -> Result<Answer>;
+
+    /// The parsed input's `Debug` representation.
+    fn show(&self, input: &dyn Any) -> Result<String>;
+
+    fn render(&self, input: &dyn Any) -> Result<Option<String>>;
+
+    /// Starts a simulation with the parameters part 1 sees.
+    fn simulate(&self, input: &dyn Any, params: &Params) -> Result<Box<dyn Simulation>>;
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> Result<&S::Input> {
+    input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| anyhow!("Input does not belong to {}", Puzzle::new(S::YEAR, S::DAY)))
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Send + Sync,
+{
+    fn puzzle(&self) -> Puzzle {
+        Puzzle::new(S::YEAR, S::DAY)
+    }
+
+    fn tasks(&self) -> Vec<Task> {
+        let mut tasks = vec![Task::new(Part::One, None), Task::new(Part::Two, None)];
+        tasks.extend(
+            self.variants()
+                .iter()
+                .map(|variant| Task::new(variant.part, Some(variant.name))),
+        );
+        tasks.sort();
+        tasks
+    }
+
+    fn params(&self) -> Vec<Param> {
+        Solution::params(self)
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        let input = input::normalize(input);
+        self.validate(&input)?;
+        Ok(Box::new(Solution::parse(self, &input)?))
+    }
+
+    fn solve(&self, input: &dyn Any, task: Task, params: &Params) -> Result<Answer> {
+        let input = downcast::<S>(input)?;
+        let params = &params.for_part(task.part).with_defaults(&Solution::params(self), task.part);
+
+        match task {
+            Task { part: Part::One, variant: None } => self.part1(input, params),
+            Task { part: Part::Two, variant: None } => self.part2(input, params),
+            Task { part, variant: Some(name) } => {
+                let variant = self
+                    .variants()
+                    .into_iter()
+                    .find(|variant| variant.part == part && variant.name == name)
+                    .ok_or_else(|| anyhow!("{} has no variant {}", self.puzzle(), task))?;
+                (variant.solve)(input, params)
+            }
+        }
+    }
+
+    fn show(&self, input: &dyn Any) -> Result<String> {
+        Ok(format!("{:#?}", downcast::<S>(input)?))
+    }
+
+    fn render(&self, input: &dyn Any) -> Result<Option<String>> {
+        Ok(Solution::render(self, downcast::<S>(input)?))
+    }
+
+    fn simulate(&self, input: &dyn Any, params: &Params) -> Result<Box<dyn Simulation>> {
+        let params = params.for_part(Part::One).with_defaults(&Solution::params(self), Part::One);
+        Solution::simulate(self, downcast::<S>(input)?, &params)
+    }
+}
+
+pub struct Registry {
+    solutions: BTreeMap<Puzzle, Arc<dyn DynSolution>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        let mut registry = Registry {
+            solutions: BTreeMap::new(),
+        };
+        years::register(&mut registry);
+        registry
+    }
+
+    pub fn add<S>(&mut self, solution: S)
+    where
+        S: Solution + Send + Sync + 'static,
+    {
+        self.solutions.insert(Puzzle::new(S::YEAR, S::DAY), Arc::new(solution));
+    }
+
+    pub fn get(&self, puzzle: Puzzle) -> Option<&dyn DynSolution> {
+        self.solutions.get(&puzzle).map(|solution| solution.as_ref())
+    }
+
+    /// Every registered solution, ordered by year and then day.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn DynSolution> {
+        self.solutions.values().map(|solution| solution.as_ref())
+    }
+
+    /// Like `iter`, but with handles that can be moved to other threads.
+    pub fn shared(&self) -> impl Iterator<Item = Arc<dyn DynSolution>> + '_ {
+        self.solutions.values().cloned()
+    }
+
+    /// The most recent year with any solutions, which commands use unless
+    /// `--year` says otherwise.
+    pub fn latest_year(&self) -> Option<u16> {
+        self.solutions.keys().map(|puzzle| puzzle.year).max()
+    }
+
+    /// Resolves a task name as used on the command line, e.g. `part1` or
+    /// `part1_optimized`, against the tasks registered for `puzzle`.
+    pub fn find_task(&self, puzzle: Puzzle, name: &str) -> Option<Task> {
+        self.get(puzzle)?
+            .tasks()
+            .into_iter()
+            .find(|task| task.to_string() == name)
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn parse_day(day: &str) -> Option<u8> {
+    day.strip_prefix("day")?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_parts_and_variants() {
+        let registry = Registry::new();
+        let puzzle = Puzzle::new(2024, 2);
+
+        assert_eq!(registry.find_task(puzzle, "part1"), Some(Task::new(Part::One, None)));
+        assert_eq!(registry.find_task(puzzle, "part2"), Some(Task::new(Part::Two, None)));
+        assert_eq!(registry.find_task(puzzle, "part1_optimized"), Some(Task::new(Part::One, Some("optimized"))));
+    }
+
+    #[test]
+    fn finds_nothing_for_unknown_tasks_and_days() {
+        let registry = Registry::new();
+        assert_eq!(registry.find_task(Puzzle::new(2024, 2), "part3"), None);
+        assert_eq!(registry.find_task(Puzzle::new(2024, 2), "part1_fast"), None);
+        assert_eq!(registry.find_task(Puzzle::new(2024, 26), "part1"), None);
+        assert_eq!(registry.find_task(Puzzle::new(2015, 2), "part1"), None);
+    }
+
+    #[test]
+    fn orders_tasks_by_part_then_variant() {
+        let registry = Registry::new();
+        let solution = registry.get(Puzzle::new(2024, 2)).unwrap();
+        let tasks: Vec<String> = solution.tasks().iter().map(Task::to_string).collect();
+        assert_eq!(tasks, ["part1", "part1_optimized", "part2", "part2_optimized"]);
+    }
+
+    #[test]
+    fn parses_days() {
+        assert_eq!(parse_day("day7"), Some(7));
+        assert_eq!(parse_day("day25"), Some(25));
+        assert_eq!(parse_day("7"), None);
+        assert_eq!(parse_day("dayx"), None);
+    }
+}
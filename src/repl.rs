@@ -0,0 +1,214 @@
+use std::any::Any;
+use std::time::Instant;
+
+use anyhow::{anyhow, bail, Context, Result};
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+
+use crate::input::InputSource;
+use crate::registry::DynSolution;
+use crate::runner::{self, catch, with_silent_panics};
+use crate::solution::{is_declared, Params, Part, Simulation};
+use crate::table;
+
+const HELP: &str = "Commands:
+    run [task]            Solve one task, or every task of the day
+    params                Show the parameters with their defaults and current values
+    set <name>=<value>    Override a parameter, or part2.<name> for one part only
+    unset <name>          Go back to a parameter's default
+    show                  Print the parsed input
+    grid                  Draw the parsed input, for days whose input is a grid
+    sim                   Start the day's simulation over from the parsed input
+    step [n]              Advance the simulation n steps (default 1) and draw it
+    reload                Read and parse the input again
+    help                  Show this message
+    quit                  Leave";
+
+struct Session<'a> {
+    solution: &'a dyn DynSolution,
+    source: &'a InputSource,
+    /// The parameters the session started with, from the example and `--param`.
+    base: Params,
+    /// Values changed with `set`, which `unset` removes again.
+    overrides: Params,
+    input: Box<dyn Any>,
+    /// The running simulation and how many steps it has taken.
+    simulation: Option<(Box<dyn Simulation>, usize)>,
+}
+
+fn load(solution: &dyn DynSolution, source: &InputSource) -> Result<Box<dyn Any>> {
+    let input = source.read(solution.puzzle())?;
+    runner::parse_input(solution, source, &input).context("Failed to parse the input")
+}
+
+impl Session<'_> {
+    fn params(&self) -> Params {
+        let mut params = self.base.clone();
+        params.extend(&self.overrides);
+        params
+    }
+
+    fn run(&self, task: Option<&str>) -> Result<()> {
+        let tasks = runner::select_tasks(self.solution, task);
+        if tasks.is_empty() {
+            bail!("No task named {}", task.unwrap_or_default());
+        }
+
+        let params = self.params();
+        for task in tasks {
+            let start = Instant::now();
+            let answer = catch(|| self.solution.solve(self.input.as_ref(), task, &params));
+            let elapsed = start.elapsed();
+            match answer {
+                Ok(answer) => println!("{}: {} ({:.2?})", task, answer, elapsed),
+                Err(err) => println!("{}: error: {:#} ({:.2?})", task, err, elapsed),
+            }
+        }
+        Ok(())
+    }
+
+    fn show_params(&self) {
+        let declared = self.solution.params();
+        if declared.is_empty() {
+            println!("No parameters");
+            return;
+        }
+
+        let params = self.params();
+        let rows: Vec<Vec<String>> = declared
+            .iter()
+            .map(|param| {
+                let part = param.part.unwrap_or(Part::One);
+                let current = params
+                    .for_part(part)
+                    .with_defaults(&declared, part)
+                    .get::<i64>(param.name)
+                    .map_or_else(|err| err.to_string(), |value| value.to_string());
+                vec![
+                    param.name.to_string(),
+                    param.part.map_or(String::from("both"), |part| part.to_string()),
+                    param.default.to_string(),
+                    current,
+                ]
+            })
+            .collect();
+        table::print(&["Parameter", "Part", "Default", "Current"], &rows);
+    }
+
+    fn set(&mut self, args: &[&str]) -> Result<()> {
+        let (name, value) = match args {
+            [pair] => pair.split_once('=').ok_or_else(|| anyhow!("Expected <name>=<value>"))?,
+            [name, value] => (*name, *value),
+            _ => bail!("Expected <name>=<value>"),
+        };
+        if !is_declared(name, &self.solution.params()) {
+            bail!("Unknown parameter {}, see params", name);
+        }
+        let value = value
+            .parse()
+            .map_err(|_| anyhow!("Value of parameter {} must be an integer, got {}", name, value))?;
+
+        self.overrides.set(name, value);
+        Ok(())
+    }
+
+    fn step(&mut self, count: usize) -> Result<()> {
+        if self.simulation.is_none() {
+            self.simulation = Some((self.solution.simulate(self.input.as_ref(), &self.params())?, 0));
+        }
+        let (simulation, steps) = self.simulation.as_mut().expect("Simulation was just started");
+
+        let mut last = None;
+        for _ in 0..count {
+            match catch(|| simulation.step()) {
+                Ok(description) => {
+                    *steps += 1;
+                    last = Some(description);
+                }
+                Err(err) => {
+                    println!("{}", simulation.render());
+                    return Err(err.context(format!("Stopped after step {}", steps)));
+                }
+            }
+        }
+
+        println!("{}", simulation.render());
+        if let Some(description) = last {
+            println!("Step {}: {}", steps, description);
+        }
+        Ok(())
+    }
+
+    /// Runs one command line, returning false when the session should end.
+    fn execute(&mut self, line: &str) -> Result<bool> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, args)) = words.split_first() else {
+            return Ok(true);
+        };
+
+        match (command, args) {
+            ("run", []) => self.run(None)?,
+            ("run", [task]) => self.run(Some(task))?,
+            ("params", []) => self.show_params(),
+            ("set", args) => self.set(args)?,
+            ("unset", [name]) => {
+                if !self.overrides.remove(name) {
+                    bail!("{} was not set in this session", name);
+                }
+            }
+            ("show", []) => println!("{}", self.solution.show(self.input.as_ref())?),
+            ("grid", []) => match self.solution.render(self.input.as_ref())? {
+                Some(grid) => println!("{}", grid),
+                None => bail!("{} has no grid to draw", self.solution.puzzle()),
+            },
+            ("sim", []) => {
+                self.simulation = None;
+                self.step(0)?;
+            }
+            ("step", []) => self.step(1)?,
+            ("step", [count]) => self.step(count.parse().map_err(|_| anyhow!("Invalid step count: {}", count))?)?,
+            ("reload", []) => {
+                self.input = load(self.solution, self.source)?;
+                self.simulation = None;
+                println!("Reloaded {}", self.solution.puzzle());
+            }
+            ("help", []) => println!("{}", HELP),
+            ("quit" | "exit", []) => return Ok(false),
+            _ => bail!("Unknown command: {}, try help", line.trim()),
+        }
+        Ok(true)
+    }
+}
+
+/// Loads the day's input once and reads commands until quit or end of input.
+pub fn repl(solution: &dyn DynSolution, source: &InputSource, overrides: &Params) -> Result<()> {
+    let base = runner::resolve_params(solution, source, overrides)?;
+    let input = load(solution, source)?;
+    let mut session = Session {
+        solution,
+        source,
+        base,
+        overrides: Params::default(),
+        input,
+        simulation: None,
+    };
+
+    let mut editor = DefaultEditor::new().context("Failed to start the line editor")?;
+    let prompt = format!("{}> ", solution.puzzle());
+    println!("Loaded {}, type help for commands", solution.puzzle());
+
+    loop {
+        let line = match editor.readline(&prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => return Ok(()),
+            Err(err) => return Err(err).context("Failed to read a command"),
+        };
+        let _ = editor.add_history_entry(line.as_str());
+
+        match with_silent_panics(|| session.execute(&line)) {
+            Ok(true) => {}
+            Ok(false) => return Ok(()),
+            Err(err) => println!("error: {:#}", err),
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
+use crate::history;
+use crate::runner::Outcome;
+use crate::solution::{Part, Puzzle};
+use crate::verify::Verdict;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ReportOptions {
+    /// Whether to write an HTML copy next to the Markdown.
+    pub html: bool,
+    /// Where the repository is browsable, e.g. `https://git.example.com/aoc/-/blob/main`,
+    /// to link source modules with. Links are relative to the repository
+    /// root without it.
+    pub source_url: Option<String>,
+}
+
+/// The width of the longest bar in the runtime chart, in characters.
+const CHART_WIDTH: usize = 40;
+
+/// Bars are drawn with full blocks and end in one of these for the fraction
+/// of a character left over, in eighths.
+const EIGHTHS: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+/// How one part of a day went.
+struct PartRow {
+    part: Part,
+    star: bool,
+    /// The answer with a mark for how it compares with the stored one.
+    result: String,
+    time: Option<Duration>,
+}
+
+struct DayRow {
+    puzzle: Puzzle,
+    parts: Vec<PartRow>,
+    parse_time: Duration,
+    source: String,
+}
+
+impl DayRow {
+    fn stars(&self) -> usize {
+        self.parts.iter().filter(|part| part.star).count()
+    }
+
+    /// The result and time of each part, then the parse time, with results
+    /// passed through `escape`.
+    fn cells(&self, escape: fn(&str) -> String) -> Vec<String> {
+        let mut cells = Vec::new();
+        for part in [Part::One, Part::Two] {
+            match self.parts.iter().find(|row| row.part == part) {
+                Some(row) => {
+                    cells.push(escape(&row.result));
+                    cells.push(row.time.map_or(String::from("-"), format_time));
+                }
+                None => cells.extend([String::from("-"), String::from("-")]),
+            }
+        }
+        cells.push(format_time(self.parse_time));
+        cells
+    }
+
+    fn total_time(&self) -> Duration {
+        self.parse_time + self.parts.iter().filter_map(|part| part.time).sum::<Duration>()
+    }
+}
+
+pub struct Report {
+    year: u16,
+    days: Vec<DayRow>,
+    generated: String,
+}
+
+fn part_row(outcome: &Outcome, verdict: &Verdict) -> PartRow {
+    let (star, result) = match (verdict, &outcome.answer) {
+        (Verdict::Match, Ok(answer)) => (true, format!("★ {}", answer)),
+        (Verdict::Unknown, Ok(answer)) => (false, format!("☆ {} (no stored answer)", answer)),
+        (Verdict::Mismatch { expected, actual }, _) => (false, format!("✗ {}, expected {}", actual, expected)),
+        (Verdict::Failed(err), _) => (false, format!("✗ {}", err)),
+        (_, Err(err)) => (false, format!("✗ {:#}", err)),
+    };
+    let time = outcome.answer.is_ok().then_some(outcome.solve_time);
+    PartRow { part: outcome.task.part, star, result, time }
+}
+
+impl Report {
+    /// Collects the reference implementation of each part from `outcomes`,
+    /// with `verdicts` from checking them against the stored answers.
+    pub fn new(year: u16, outcomes: &[Outcome], verdicts: &[Verdict], options: &ReportOptions) -> Self {
+        let mut days: BTreeMap<Puzzle, DayRow> = BTreeMap::new();
+        for (outcome, verdict) in outcomes.iter().zip(verdicts) {
+            if outcome.task.variant.is_some() {
+                continue;
+            }
+
+            let day = days.entry(outcome.puzzle).or_insert_with(|| DayRow {
+                puzzle: outcome.puzzle,
+                parts: Vec::new(),
+                parse_time: outcome.parse_time,
+                source: source_link(outcome.puzzle, options.source_url.as_deref()),
+            });
+            day.parts.push(part_row(outcome, verdict));
+        }
+
+        let generated = match history::commit() {
+            Some(commit) => format!("{} UTC at commit {}", history::format_time(history::now()), commit),
+            None => format!("{} UTC", history::format_time(history::now())),
+        };
+
+        Report { year, days: days.into_values().collect(), generated }
+    }
+
+    fn stars(&self) -> usize {
+        self.days.iter().map(DayRow::stars).sum()
+    }
+
+    fn total_time(&self) -> Duration {
+        self.days.iter().map(DayRow::total_time).sum()
+    }
+
+    fn summary(&self) -> String {
+        format!(
+            "{} of {} stars from {} days, solved in {:.2?} in total.",
+            self.stars(),
+            2 * self.days.len(),
+            self.days.len(),
+            self.total_time()
+        )
+    }
+
+    /// Each day's total time as a bar, scaled to the slowest day.
+    fn chart(&self) -> String {
+        let slowest = self.days.iter().map(DayRow::total_time).max().unwrap_or_default();
+        self.days
+            .iter()
+            .map(|day| {
+                let time = day.total_time();
+                let eighths = if slowest.is_zero() {
+                    0
+                } else {
+                    (time.as_secs_f64() / slowest.as_secs_f64() * (CHART_WIDTH * 8) as f64).round() as usize
+                };
+                let bar = format!("{}{}", "█".repeat(eighths / 8), EIGHTHS[eighths % 8]);
+                format!("day {:>2} │{:<width$} {}\n", day.puzzle.day, bar, format_time(time), width = CHART_WIDTH)
+            })
+            .collect()
+    }
+
+    pub fn markdown(&self) -> String {
+        let mut rows = String::new();
+        for day in &self.days {
+            let mut cells = vec![format!("[{}]({})", day.puzzle.day, day.source), stars(day.stars())];
+            cells.extend(day.cells(escape_markdown));
+            rows.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+
+        format!(
+            "# Advent of Code {year}
+
+{summary}
+
+| Day | Stars | Part 1 | Time | Part 2 | Time | Parse |
+|----:|:-----:|--------|-----:|--------|-----:|------:|
+{rows}
+★ matches the stored answer, ☆ has no stored answer yet, ✗ is wrong or failed.
+
+## Runtime per day
+
+Parsing and both parts, scaled to the slowest day.
+
+```text
+{chart}```
+
+Generated {generated}.
+",
+            year = self.year,
+            summary = self.summary(),
+            chart = self.chart(),
+            generated = self.generated,
+        )
+    }
+
+    pub fn html(&self) -> String {
+        let mut rows = String::new();
+        for day in &self.days {
+            let mut cells = vec![
+                format!("<a href=\"{}\">{}</a>", escape_html(&day.source), day.puzzle.day),
+                stars(day.stars()),
+            ];
+            cells.extend(day.cells(escape_html));
+            let cells: Vec<String> = cells.iter().map(|cell| format!("<td>{}</td>", cell)).collect();
+            rows.push_str(&format!("      <tr>{}</tr>\n", cells.concat()));
+        }
+
+        format!(
+            "<!DOCTYPE html>
+<html>
+  <head>
+    <meta charset=\"utf-8\">
+    <title>Advent of Code {year}</title>
+    <style>
+      body {{ font-family: sans-serif; }}
+      table {{ border-collapse: collapse; }}
+      th, td {{ border: 1px solid #ccc; padding: 0.2em 0.6em; }}
+    </style>
+  </head>
+  <body>
+    <h1>Advent of Code {year}</h1>
+    <p>{summary}</p>
+    <table>
+      <tr><th>Day</th><th>Stars</th><th>Part 1</th><th>Time</th><th>Part 2</th><th>Time</th><th>Parse</th></tr>
+{rows}    </table>
+    <p>★ matches the stored answer, ☆ has no stored answer yet, ✗ is wrong or failed.</p>
+    <h2>Runtime per day</h2>
+    <p>Parsing and both parts, scaled to the slowest day.</p>
+    <pre>{chart}</pre>
+    <p>Generated {generated}.</p>
+  </body>
+</html>
+",
+            year = self.year,
+            summary = escape_html(&self.summary()),
+            chart = escape_html(&self.chart()),
+            generated = escape_html(&self.generated),
+        )
+    }
+
+    /// Writes `report-YYYY.md`, and `report-YYYY.html` when asked to,
+    /// returning the paths written.
+    pub fn write(&self, options: &ReportOptions) -> Result<Vec<PathBuf>> {
+        let mut written = Vec::new();
+        let mut files = vec![(PathBuf::from(format!("report-{}.md", self.year)), self.markdown())];
+        if options.html {
+            files.push((PathBuf::from(format!("report-{}.html", self.year)), self.html()));
+        }
+
+        for (path, contents) in files {
+            fs::write(&path, contents).with_context(|| format!("Failed to write {}", path.display()))?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
+/// A time, or `-` for none at all, as for a day whose input could not be read.
+fn format_time(time: Duration) -> String {
+    if time.is_zero() {
+        String::from("-")
+    } else {
+        format!("{:.2?}", time)
+    }
+}
+
+fn stars(count: usize) -> String {
+    format!("{}{}", "★".repeat(count), "☆".repeat(2 - count.min(2)))
+}
+
+fn source_link(puzzle: Puzzle, source_url: Option<&str>) -> String {
+    let path = format!("src/years/y{}/day{}/mod.rs", puzzle.year, puzzle.day);
+    match source_url {
+        Some(url) => format!("{}/{}", url.trim_end_matches('/'), path),
+        None => path,
+    }
+}
+
+/// Keeps errors and answers from breaking out of their table cell.
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
@@ -0,0 +1,405 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::input::InputSource;
+use crate::memory::{self, Usage};
+use crate::output;
+use crate::profile;
+use crate::registry::{parse_day, DynSolution, Registry};
+use crate::solution::{is_declared, Answer, Params, Puzzle, Task};
+use crate::table;
+
+/// Which days a `run` command covers: `all`, `day5..day12` or `day16`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DaySelector {
+    All,
+    Range(u8, u8),
+    Day(u8),
+}
+
+impl DaySelector {
+    pub fn parse(selector: &str) -> Option<Self> {
+        if selector == "all" {
+            return Some(DaySelector::All);
+        }
+
+        match selector.split_once("..") {
+            Some((from, to)) => Some(DaySelector::Range(parse_day(from)?, parse_day(to)?)),
+            None => Some(DaySelector::Day(parse_day(selector)?)),
+        }
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        match *self {
+            DaySelector::All => true,
+            DaySelector::Range(from, to) => (from..=to).contains(&day),
+            DaySelector::Day(selected) => selected == day,
+        }
+    }
+}
+
+/// The puzzles a command covers: selected days of one year, or of every year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selection {
+    pub year: Option<u16>,
+    pub days: DaySelector,
+}
+
+impl Selection {
+    pub fn contains(&self, puzzle: Puzzle) -> bool {
+        self.year.is_none_or(|year| year == puzzle.year) && self.days.contains(puzzle.day)
+    }
+}
+
+pub struct Outcome {
+    pub puzzle: Puzzle,
+    pub task: Task,
+    pub answer: Result<Answer>,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    /// What the task logged while it ran. Logs from parsing belong to the
+    /// day's first task.
+    pub log: String,
+    /// Heap usage of the solve, when `--memory` turned counting on.
+    pub memory: Option<Usage>,
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("unknown panic")
+    }
+}
+
+/// Runs `f`, turning a panic into an error so one broken solution does not
+/// take the rest of a run down with it.
+pub fn catch<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(anyhow!("panicked: {}", panic_message(payload))))
+}
+
+/// The parameters `solution` runs with: an example's own, replaced by any
+/// `--param` overrides the solution declares.
+pub fn resolve_params(solution: &dyn DynSolution, source: &InputSource, overrides: &Params) -> Result<Params> {
+    let declared = solution.params();
+
+    let mut params = source.params(solution.puzzle())?;
+    params
+        .check(&declared)
+        .with_context(|| format!("Invalid example parameters for {}", solution.puzzle()))?;
+    params.extend(&overrides.declared_by(&declared));
+
+    Ok(params)
+}
+
+/// Fails unless every `--param` override is declared by at least one of the
+/// selected days, so a typo does not silently run with the defaults.
+pub fn check_overrides(registry: &Registry, selection: Selection, overrides: &Params) -> Result<(), String> {
+    let selected: Vec<&dyn DynSolution> = registry
+        .iter()
+        .filter(|solution| selection.contains(solution.puzzle()))
+        .collect();
+
+    for name in overrides.names() {
+        if !selected.iter().any(|solution| is_declared(name, &solution.params())) {
+            return Err(format!("No selected day has a parameter named {}, see --help for a day's parameters", name));
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses `input` with panics caught, naming where it came from in parse errors.
+pub fn parse_input(solution: &dyn DynSolution, source: &InputSource, input: &str) -> Result<Box<dyn Any>> {
+    catch(|| solution.parse(input)).map_err(|mut err| {
+        if let Some(Error::Parse(parse)) = err.downcast_mut::<Error>() {
+            parse.file = Some(source.describe(solution.puzzle()));
+        }
+        err
+    })
+}
+
+/// A copy of a parse failure for each task it stops, keeping parse errors
+/// intact so they can still be shown in full.
+fn duplicate(err: &anyhow::Error) -> anyhow::Error {
+    match err.downcast_ref::<Error>() {
+        Some(Error::Parse(parse)) => Error::Parse(parse.clone()).into(),
+        _ => anyhow!("{:#}", err),
+    }
+}
+
+/// Reads and parses the day's input once and runs each of `tasks` against it,
+/// capturing what they log. Reading the input is not counted in either timing.
+pub fn run_day(solution: &dyn DynSolution, source: &InputSource, overrides: &Params, tasks: &[Task]) -> Vec<Outcome> {
+    let day_span = profile::span(&solution.puzzle().to_string());
+
+    let mut parse_time = Duration::ZERO;
+    let (parsed, mut log) = output::capture(|| -> Result<_> {
+        let input = source.read(solution.puzzle())?;
+        let params = resolve_params(solution, source, overrides)?;
+        let start = Instant::now();
+        let parsed = {
+            let _span = profile::span("parse");
+            parse_input(solution, source, &input)
+        };
+        parse_time = start.elapsed();
+        Ok((parsed?, params))
+    });
+
+    let outcomes = tasks
+        .iter()
+        .map(|&task| {
+            let ((answer, solve_time, memory), solve_log) = output::capture(|| match &parsed {
+                Ok((input, params)) => {
+                    let _span = profile::span(&task.to_string());
+                    let start = Instant::now();
+                    let (answer, memory) = memory::measure(|| catch(|| solution.solve(input.as_ref(), task, params)));
+                    (answer, start.elapsed(), memory)
+                }
+                Err(err) => (Err(duplicate(err)), Duration::ZERO, None),
+            });
+            log.push_str(&solve_log);
+
+            Outcome {
+                puzzle: solution.puzzle(),
+                task,
+                answer,
+                parse_time,
+                solve_time,
+                log: std::mem::take(&mut log),
+                memory,
+            }
+        })
+        .collect();
+
+    drop(day_span);
+    profile::collect();
+    outcomes
+}
+
+/// Runs `f` with the panic hook silenced, so panics caught by `catch` show up
+/// as errors in the results instead of also being printed as they happen.
+pub fn with_silent_panics<T>(f: impl FnOnce() -> T) -> T {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = f();
+    panic::set_hook(default_hook);
+    result
+}
+
+/// The tasks of `solution` matching an optional task name such as `part1`.
+pub fn select_tasks(solution: &dyn DynSolution, task: Option<&str>) -> Vec<Task> {
+    solution
+        .tasks()
+        .into_iter()
+        .filter(|t| task.is_none_or(|name| t.to_string() == name))
+        .collect()
+}
+
+/// The number of days `run` works on at the same time unless `--jobs` says
+/// otherwise: one per available core.
+pub fn default_jobs() -> usize {
+    std::thread::available_parallelism().map_or(1, |jobs| jobs.get())
+}
+
+/// Runs every registered task of every selected puzzle, with up to `jobs`
+/// days at a time. Outcomes come back in year and day order.
+pub fn run_all(
+    registry: &Registry,
+    selection: Selection,
+    task: Option<&str>,
+    source: &InputSource,
+    overrides: &Params,
+    jobs: usize,
+) -> Vec<Outcome> {
+    let solutions = registry
+        .shared()
+        .filter(|solution| selection.contains(solution.puzzle()))
+        .collect();
+    run_solutions(solutions, task, source, overrides, jobs)
+}
+
+/// Runs the tasks of each of `solutions` matching `task`, with up to `jobs`
+/// days at a time on tokio's blocking pool. Outcomes come back in the order
+/// of `solutions` however the days finish.
+pub fn run_solutions(
+    solutions: Vec<Arc<dyn DynSolution>>,
+    task: Option<&str>,
+    source: &InputSource,
+    overrides: &Params,
+    jobs: usize,
+) -> Vec<Outcome> {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .max_blocking_threads(jobs)
+        .build()
+        .expect("Failed to start the tokio runtime");
+
+    with_silent_panics(|| {
+        runtime.block_on(async {
+            let handles: Vec<_> = solutions
+                .into_iter()
+                .map(|solution| {
+                    let tasks = select_tasks(solution.as_ref(), task);
+                    let source = source.clone();
+                    let overrides = overrides.clone();
+                    tokio::task::spawn_blocking(move || run_day(solution.as_ref(), &source, &overrides, &tasks))
+                })
+                .collect();
+
+            let mut outcomes = Vec::new();
+            for handle in handles {
+                outcomes.extend(handle.await.expect("Panics are caught inside run_day"));
+            }
+            outcomes
+        })
+    })
+}
+
+/// Prints to stderr what each task logged while it ran, under a heading
+/// naming the task.
+pub fn print_logs(outcomes: &[Outcome]) {
+    for outcome in outcomes.iter().filter(|outcome| !outcome.log.is_empty()) {
+        eprintln!("Log of {} {}:", outcome.puzzle, outcome.task);
+        eprintln!("{}", outcome.log.trim_end());
+        eprintln!();
+    }
+}
+
+pub fn print_table(outcomes: &[Outcome]) {
+    let rows: Vec<Vec<String>> = outcomes
+        .iter()
+        .map(|outcome| {
+            let answer = match &outcome.answer {
+                Ok(answer) => answer.to_string(),
+                Err(err) => format!("error: {:#}", err),
+            };
+            vec![
+                outcome.puzzle.year.to_string(),
+                outcome.puzzle.day.to_string(),
+                outcome.task.part.number().to_string(),
+                outcome.task.variant.unwrap_or("-").to_string(),
+                answer,
+                format!("{:.2?}", outcome.parse_time),
+                format!("{:.2?}", outcome.solve_time),
+            ]
+        })
+        .collect();
+
+    table::print(&["Year", "Day", "Part", "Variant", "Answer", "Parse", "Solve"], &rows);
+
+    let failed = outcomes.iter().filter(|outcome| outcome.answer.is_err()).count();
+    println!();
+    println!("{} solved, {} failed", outcomes.len() - failed, failed);
+}
+
+/// Ranks the measured tasks by peak heap usage, largest first.
+pub fn print_memory(outcomes: &[Outcome]) {
+    let mut measured: Vec<(&Outcome, Usage)> = outcomes
+        .iter()
+        .filter_map(|outcome| Some((outcome, outcome.memory?)))
+        .collect();
+    measured.sort_by_key(|&(outcome, usage)| (std::cmp::Reverse(usage.peak), outcome.puzzle, outcome.task));
+
+    let rows: Vec<Vec<String>> = measured
+        .iter()
+        .map(|(outcome, usage)| {
+            vec![
+                outcome.puzzle.year.to_string(),
+                outcome.puzzle.day.to_string(),
+                outcome.task.part.number().to_string(),
+                outcome.task.variant.unwrap_or("-").to_string(),
+                memory::format_bytes(usage.peak),
+                usage.allocations.to_string(),
+                memory::format_bytes(usage.allocated),
+            ]
+        })
+        .collect();
+
+    table::print(&["Year", "Day", "Part", "Variant", "Peak heap", "Allocations", "Allocated"], &rows);
+}
+
+/// One line of `--format json` output.
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    variant: Option<&'a str>,
+    answer: Option<String>,
+    parse_ns: u64,
+    solve_ns: u64,
+    error: Option<String>,
+    log: Option<&'a str>,
+    peak_bytes: Option<u64>,
+    allocations: Option<u64>,
+    allocated_bytes: Option<u64>,
+}
+
+impl<'a> From<&'a Outcome> for Record<'a> {
+    fn from(outcome: &'a Outcome) -> Self {
+        Record {
+            year: outcome.puzzle.year,
+            day: outcome.puzzle.day,
+            part: outcome.task.part.number(),
+            variant: outcome.task.variant,
+            answer: outcome.answer.as_ref().ok().map(|answer| answer.to_string()),
+            parse_ns: outcome.parse_time.as_nanos() as u64,
+            solve_ns: outcome.solve_time.as_nanos() as u64,
+            error: outcome.answer.as_ref().err().map(|err| format!("{:#}", err)),
+            log: Some(outcome.log.as_str()).filter(|log| !log.is_empty()),
+            peak_bytes: outcome.memory.map(|usage| usage.peak),
+            allocations: outcome.memory.map(|usage| usage.allocations),
+            allocated_bytes: outcome.memory.map(|usage| usage.allocated),
+        }
+    }
+}
+
+/// Prints one JSON object per line, one per outcome.
+pub fn print_json(outcomes: &[Outcome]) {
+    for outcome in outcomes {
+        let record = Record::from(outcome);
+        println!("{}", serde_json::to_string(&record).expect("Records always serialize"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overrides(name: &str) -> Params {
+        let mut params = Params::default();
+        params.set(name, 1);
+        params
+    }
+
+    #[test]
+    fn accepts_parameters_of_a_selected_day() {
+        let registry = Registry::new();
+        let day18 = Selection { year: Some(2024), days: DaySelector::Day(18) };
+        assert!(check_overrides(&registry, day18, &overrides("size")).is_ok());
+        assert!(check_overrides(&registry, day18, &overrides("part1.bytes")).is_ok());
+
+        let all = Selection { year: None, days: DaySelector::All };
+        assert!(check_overrides(&registry, all, &overrides("min_saving")).is_ok());
+    }
+
+    #[test]
+    fn rejects_parameters_no_selected_day_declares() {
+        let registry = Registry::new();
+        let day18 = Selection { year: Some(2024), days: DaySelector::Day(18) };
+        assert_eq!(
+            check_overrides(&registry, day18, &overrides("min_saving")).unwrap_err(),
+            "No selected day has a parameter named min_saving, see --help for a day's parameters"
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::input;
+use crate::solution::Puzzle;
+
+const YEARS_MOD: &str = "src/years/mod.rs";
+
+fn mod_rs(Puzzle { year, day }: Puzzle) -> String {
+    format!(
+        "pub mod utils;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::solution::{{Answer, Params, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Input = Vec<String>;
+
+    const YEAR: u16 = {year};
+
+    const DAY: u8 = {day};
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {{
+        Ok(utils::parse_input(input))
+    }}
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {{
+        part1::solve(input)
+    }}
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {{
+        part2::solve(input)
+    }}
+}}
+"
+    )
+}
+
+fn part_rs(day: u8, part: u8) -> String {
+    format!(
+        "use anyhow::{{anyhow, Result}};
+
+use crate::solution::Answer;
+
+pub fn solve(input: &[String]) -> Result<Answer> {{
+    let _ = input;
+
+    Err(anyhow!(\"Day {day} part {part} is not solved yet\"))
+}}
+"
+    )
+}
+
+const UTILS_RS: &str = "pub fn parse_input(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+";
+
+const ANSWERS_TOML: &str = "# Accepted answers for input.txt, checked by `verify`.
+# part1 = 0
+# part2 = 0
+";
+
+const EXAMPLES_TOML: &str = "# Expected answers and parameters for each example, run with `--example example1`.
+[example1]
+# part1 = 0
+# part2 = 0
+
+[example1.params]
+";
+
+/// Writes `contents` to a new file, failing instead of replacing one that
+/// already exists.
+fn create(path: &Path, contents: &str) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .with_context(|| format!("Failed to create {}", path.display()))?;
+    file.write_all(contents.as_bytes())
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Adds `entry` to the `name! { ... }` list in `source`, read from `path`,
+/// keeping the entries in the order of the number in them.
+fn add_to_list(source: &str, path: &Path, name: &str, entry: &str) -> Result<String> {
+    let number = |entry: &str| -> u32 {
+        let digits: String = entry
+            .chars()
+            .skip_while(|c| !c.is_ascii_digit())
+            .take_while(char::is_ascii_digit)
+            .collect();
+        digits.parse().unwrap_or(0)
+    };
+
+    let header = format!("{}! {{", name);
+    let start = source
+        .find(&header)
+        .ok_or_else(|| anyhow!("No {}! list in {}", name, path.display()))?;
+    let end = start
+        + source[start..]
+            .find("\n}")
+            .ok_or_else(|| anyhow!("Unterminated {}! list in {}", name, path.display()))?;
+
+    let mut entries: Vec<&str> = source[start..end]
+        .lines()
+        .skip(1)
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    if entries.iter().any(|existing| number(existing) == number(entry)) {
+        bail!("{} is already listed in {}", entry.trim_end_matches(','), path.display());
+    }
+    entries.push(entry);
+    entries.sort_by_key(|entry| number(entry));
+
+    let entries: String = entries.iter().map(|entry| format!("\n    {}", entry)).collect();
+    Ok(format!("{}{}{}{}", &source[..start], header, entries, &source[end..]))
+}
+
+/// Generates the module skeleton for `puzzle`, registers it, along with its
+/// year if that is new, and creates stub input files, returning the paths
+/// that were written. Refuses to touch a day that already has a module.
+pub fn new_day(puzzle: Puzzle) -> Result<Vec<PathBuf>> {
+    let Puzzle { year, day } = puzzle;
+    if !(1..=25).contains(&day) {
+        bail!("Advent of Code days run from 1 to 25, not {}", day);
+    }
+
+    let years_path = Path::new(YEARS_MOD);
+    let years_mod = fs::read_to_string(years_path)
+        .with_context(|| format!("Failed to read {}, run new from the repository root", YEARS_MOD))?;
+
+    let year_dir = PathBuf::from(format!("src/years/y{}", year));
+    let module = year_dir.join(format!("day{}", day));
+    if module.exists() {
+        bail!("{} already exists at {}", puzzle, module.display());
+    }
+
+    let year_path = year_dir.join("mod.rs");
+    let new_year = !year_path.exists();
+    let year_mod = if new_year {
+        String::from("days! {\n}\n")
+    } else {
+        fs::read_to_string(&year_path).with_context(|| format!("Failed to read {}", year_path.display()))?
+    };
+    let year_mod = add_to_list(&year_mod, &year_path, "days", &format!("day{} => Day{},", day, day))?;
+
+    fs::create_dir_all(&module).with_context(|| format!("Failed to create {}", module.display()))?;
+    let mut written = Vec::new();
+    for (name, contents) in [
+        ("mod.rs", mod_rs(puzzle)),
+        ("part1.rs", part_rs(day, 1)),
+        ("part2.rs", part_rs(day, 2)),
+        ("utils.rs", UTILS_RS.to_string()),
+    ] {
+        let path = module.join(name);
+        create(&path, &contents)?;
+        written.push(path);
+    }
+
+    fs::write(&year_path, year_mod).with_context(|| format!("Failed to write {}", year_path.display()))?;
+    written.push(year_path);
+
+    if new_year {
+        let years_mod = add_to_list(&years_mod, years_path, "years", &format!("y{},", year))?;
+        fs::write(years_path, years_mod).with_context(|| format!("Failed to write {}", YEARS_MOD))?;
+        written.push(years_path.to_path_buf());
+    }
+
+    // Inputs may already be there, e.g. fetched before the day was started,
+    // so only the missing stubs are created.
+    let inputs = input::dir(puzzle);
+    fs::create_dir_all(&inputs).with_context(|| format!("Failed to create {}", inputs.display()))?;
+    for (name, contents) in [("example1.txt", ""), ("answers.toml", ANSWERS_TOML), ("examples.toml", EXAMPLES_TOML)] {
+        let path = inputs.join(name);
+        if !path.exists() {
+            create(&path, contents)?;
+            written.push(path);
+        }
+    }
+
+    Ok(written)
+}
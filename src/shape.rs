@@ -0,0 +1,121 @@
+use crate::error::Error;
+
+/// Fails unless `grid`, a slice of `input`, has at least one row and every
+/// row is as wide as the first.
+pub fn rectangular(input: &str, grid: &str) -> Result<(), Error> {
+    let mut rows = grid.lines();
+    let width = match rows.next() {
+        Some(first) if !first.is_empty() => first.chars().count(),
+        _ => return Err(Error::missing(input, &grid[..0], "Expected a grid")),
+    };
+
+    for row in rows {
+        let found = row.chars().count();
+        let message = format!("Expected a row of {} tiles like the first, found {}", width, found);
+        match row.char_indices().nth(width) {
+            Some((extra, _)) => return Err(Error::parse(input, &row[extra..], message)),
+            None if found < width => return Err(Error::missing(input, row, message)),
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+/// Fails on the first tile of `grid`, a slice of `input`, that is not one
+/// of `allowed`.
+pub fn only(input: &str, grid: &str, allowed: &str) -> Result<(), Error> {
+    match grid.char_indices().find(|&(_, tile)| tile != '\n' && !allowed.contains(tile)) {
+        Some((i, tile)) => {
+            let expected: Vec<String> = allowed.chars().map(String::from).collect();
+            Err(Error::parse(
+                input,
+                &grid[i..i + tile.len_utf8()],
+                format!("Expected one of {}", expected.join(", ")),
+            ))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Fails unless `tile` occurs exactly once in `grid`, a slice of `input`,
+/// where `what` names it for the error, e.g. "start S".
+pub fn exactly_one(input: &str, grid: &str, tile: char, what: &str) -> Result<(), Error> {
+    let mut found = grid.match_indices(tile);
+    if found.next().is_none() {
+        return Err(Error::missing(input, grid, format!("Missing the {}", what)));
+    }
+    match found.next() {
+        Some((i, text)) => Err(Error::parse(input, &grid[i..i + text.len()], format!("Expected only one {}", what))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn located(result: Result<(), Error>) -> (usize, usize, String, String) {
+        match result {
+            Err(Error::Parse(err)) => (err.line, err.column, err.text, err.message),
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn accepts_a_rectangle() {
+        assert!(rectangular("#S.\n..E\n###", "#S.\n..E\n###").is_ok());
+    }
+
+    #[test]
+    fn points_at_the_extra_tiles_of_a_long_row() {
+        let grid = "###\n#...#\n###";
+        assert_eq!(
+            located(rectangular(grid, grid)),
+            (2, 4, String::from(".#"), String::from("Expected a row of 3 tiles like the first, found 5"))
+        );
+    }
+
+    #[test]
+    fn points_past_the_end_of_a_short_row() {
+        let grid = "###\n#.#\n#";
+        assert_eq!(
+            located(rectangular(grid, grid)),
+            (3, 2, String::new(), String::from("Expected a row of 3 tiles like the first, found 1"))
+        );
+    }
+
+    #[test]
+    fn rejects_an_empty_grid() {
+        assert_eq!(located(rectangular("", "")), (1, 1, String::new(), String::from("Expected a grid")));
+    }
+
+    #[test]
+    fn checks_a_grid_within_the_input() {
+        let input = "##\n##\n\n<>";
+        assert!(rectangular(input, &input[..5]).is_ok());
+    }
+
+    #[test]
+    fn rejects_unknown_tiles() {
+        let grid = "#.#\n#é#";
+        assert_eq!(
+            located(only(grid, grid, "#.")),
+            (2, 2, String::from("é"), String::from("Expected one of #, ."))
+        );
+        assert!(only("#.\n.#", "#.\n.#", "#.").is_ok());
+    }
+
+    #[test]
+    fn requires_exactly_one_tile() {
+        let grid = "#S.\n.SE";
+        assert_eq!(
+            located(exactly_one(grid, grid, 'S', "start S")),
+            (2, 2, String::from("S"), String::from("Expected only one start S"))
+        );
+        assert_eq!(
+            located(exactly_one(grid, grid, 'X', "exit X")),
+            (2, 4, String::new(), String::from("Missing the exit X"))
+        );
+        assert!(exactly_one(grid, grid, 'E', "end E").is_ok());
+    }
+}
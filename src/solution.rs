@@ -0,0 +1,348 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug};
+
+use anyhow::{anyhow, bail, Result};
+
+/// One day of one event, e.g. 2024 day 5.
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Puzzle {
+    pub fn new(year: u16, day: u8) -> Self {
+        Puzzle { year, day }
+    }
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)
+    }
+}
+
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part{}", self.number())
+    }
+}
+
+/// A single runnable piece of a day: a part, optionally with a named variant
+/// such as day 2's `part1_optimized`.
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Copy)]
+pub struct Task {
+    pub part: Part,
+    pub variant: Option<&'static str>,
+}
+
+impl Task {
+    pub fn new(part: Part, variant: Option<&'static str>) -> Self {
+        Task { part, variant }
+    }
+}
+
+impl fmt::Display for Task {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.variant {
+            Some(variant) => write!(f, "{}_{}", self.part, variant),
+            None => write!(f, "{}", self.part),
+        }
+    }
+}
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+    Coord(i64, i64),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{}", value),
+            Answer::UInt(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Coord(x, y) => write!(f, "{},{}", x, y),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Int(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::UInt(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::UInt(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// Named puzzle parameters, such as a grid size that differs between the
+/// examples and the real input. A name prefixed with a part, as in
+/// `part2.min_saving`, only applies to that part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, i64>,
+}
+
+impl Params {
+    pub fn set(&mut self, name: &str, value: i64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    /// Removes the value of `name`, returning whether it was set.
+    pub fn remove(&mut self, name: &str) -> bool {
+        self.values.remove(name).is_some()
+    }
+
+    /// The parameters one part sees, with its own prefixed values taking
+    /// precedence over the shared ones.
+    pub fn for_part(&self, part: Part) -> Params {
+        let prefix = format!("{}.", part);
+        let mut values: BTreeMap<String, i64> = self
+            .values
+            .iter()
+            .filter(|(name, _)| !name.contains('.'))
+            .map(|(name, &value)| (name.clone(), value))
+            .collect();
+
+        for (name, &value) in &self.values {
+            if let Some(name) = name.strip_prefix(&prefix) {
+                values.insert(name.to_string(), value);
+            }
+        }
+
+        Params { values }
+    }
+
+    /// Fills in the declared default of every parameter `part` uses that has
+    /// no value yet.
+    pub fn with_defaults(mut self, declared: &[Param], part: Part) -> Params {
+        for param in declared.iter().filter(|param| param.part.is_none_or(|p| p == part)) {
+            self.values.entry(param.name.to_string()).or_insert(param.default);
+        }
+        self
+    }
+
+    /// Sets every value of `other`, replacing existing ones.
+    pub fn extend(&mut self, other: &Params) {
+        self.values.extend(other.values.iter().map(|(name, &value)| (name.clone(), value)));
+    }
+
+    /// The given names, including any part prefix.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    /// Only the values whose name is in `declared`.
+    pub fn declared_by(&self, declared: &[Param]) -> Params {
+        let values = self
+            .values
+            .iter()
+            .filter(|(name, _)| is_declared(name, declared))
+            .map(|(name, &value)| (name.clone(), value))
+            .collect();
+        Params { values }
+    }
+
+    /// Fails on the first value whose name is not in `declared`.
+    pub fn check(&self, declared: &[Param]) -> Result<()> {
+        match self.names().find(|name| !is_declared(name, declared)) {
+            Some(name) => Err(anyhow!("Unknown parameter {}", name)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn get<T>(&self, name: &str) -> Result<T>
+    where
+        T: TryFrom<i64>,
+    {
+        let value = *self
+            .values
+            .get(name)
+            .ok_or_else(|| anyhow!("Parameter {} is not declared", name))?;
+        T::try_from(value).map_err(|_| anyhow!("Parameter {} is out of range: {}", name, value))
+    }
+}
+
+/// Whether `name`, with any part prefix removed, is one of `declared`.
+pub fn is_declared(name: &str, declared: &[Param]) -> bool {
+    let name = [Part::One, Part::Two]
+        .iter()
+        .find_map(|part| name.strip_prefix(&format!("{}.", part)))
+        .unwrap_or(name);
+    declared.iter().any(|param| param.name == name)
+}
+
+/// A parameter a solution accepts, overridable with `--param name=value`.
+/// A parameter with a `part` only applies to that part, which lets the
+/// parts of a day declare different defaults under the same name.
+pub struct Param {
+    pub name: &'static str,
+    pub part: Option<Part>,
+    pub default: i64,
+    pub description: &'static str,
+}
+
+/// An alternative implementation of one part, run against the same parsed input.
+pub struct Variant<I> {
+    pub part: Part,
+    pub name: &'static str,
+    pub solve: fn(&I, &Params) -> Result<Answer>,
+}
+
+/// A puzzle's process run one step at a time, such as the robots of 2024
+/// day 14 moving for a second.
+pub trait Simulation {
+    /// Advances one step, describing what happened.
+    fn step(&mut self) -> Result<String>;
+
+    fn render(&self) -> String;
+}
+
+pub trait Solution {
+    type Input: Debug + 'static;
+
+    const YEAR: u16;
+
+    const DAY: u8;
+
+    /// Checks the shape of the input before `parse`, such as a grid being
+    /// rectangular, so a malformed input fails before solving starts.
+    fn validate(&self, _input: &str) -> Result<()> {
+        Ok(())
+    }
+
+    /// Parses the input as `input::normalize` leaves it, after `validate`
+    /// accepted it.
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input, params: &Params) -> Result<Answer>;
+
+    fn part2(&self, input: &Self::Input, params: &Params) -> Result<Answer>;
+
+    fn params(&self) -> Vec<Param> {
+        Vec::new()
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input>> {
+        Vec::new()
+    }
+
+    /// Draws the parsed input, for days whose input is a grid.
+    fn render(&self, _input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    /// Starts a step-by-step simulation of the input, for days that have one.
+    fn simulate(&self, _input: &Self::Input, _params: &Params) -> Result<Box<dyn Simulation>> {
+        bail!("Day {} has no step-by-step simulation", Self::DAY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(values: &[(&str, i64)]) -> Params {
+        let mut params = Params::default();
+        for &(name, value) in values {
+            params.set(name, value);
+        }
+        params
+    }
+
+    /// Like day 20's: one shared parameter and one with a default per part.
+    fn declared() -> Vec<Param> {
+        vec![
+            Param { name: "min_saving", part: None, default: 100, description: "" },
+            Param { name: "cheat_length", part: Some(Part::One), default: 2, description: "" },
+            Param { name: "cheat_length", part: Some(Part::Two), default: 20, description: "" },
+        ]
+    }
+
+    fn resolved(given: &[(&str, i64)], part: Part) -> (i64, i64) {
+        let params = params(given).for_part(part).with_defaults(&declared(), part);
+        (params.get("min_saving").unwrap(), params.get("cheat_length").unwrap())
+    }
+
+    #[test]
+    fn falls_back_to_each_part_s_default() {
+        assert_eq!(resolved(&[], Part::One), (100, 2));
+        assert_eq!(resolved(&[], Part::Two), (100, 20));
+    }
+
+    #[test]
+    fn applies_shared_values_to_both_parts() {
+        let given = [("min_saving", 50), ("cheat_length", 6)];
+        assert_eq!(resolved(&given, Part::One), (50, 6));
+        assert_eq!(resolved(&given, Part::Two), (50, 6));
+    }
+
+    #[test]
+    fn prefers_a_part_s_own_values() {
+        let given = [("min_saving", 50), ("part2.min_saving", 70), ("part1.cheat_length", 3)];
+        assert_eq!(resolved(&given, Part::One), (50, 3));
+        assert_eq!(resolved(&given, Part::Two), (70, 20));
+    }
+
+    #[test]
+    fn rejects_unknown_parameters() {
+        assert!(params(&[("min_saving", 1), ("part2.cheat_length", 1)]).check(&declared()).is_ok());
+        let err = params(&[("min_saving", 1), ("size", 7)]).check(&declared()).unwrap_err();
+        assert_eq!(err.to_string(), "Unknown parameter size");
+        assert!(params(&[("part3.min_saving", 1)]).check(&declared()).is_err());
+    }
+
+    #[test]
+    fn keeps_only_declared_values() {
+        let given = params(&[("size", 7), ("part1.cheat_length", 3)]);
+        assert_eq!(given.declared_by(&declared()), params(&[("part1.cheat_length", 3)]));
+    }
+
+    #[test]
+    fn converts_values_to_the_requested_type() {
+        let given = params(&[("size", 7), ("negative", -1)]);
+        assert_eq!(given.get::<usize>("size").unwrap(), 7);
+        assert_eq!(given.get::<usize>("negative").unwrap_err().to_string(), "Parameter negative is out of range: -1");
+        assert_eq!(given.get::<i64>("bytes").unwrap_err().to_string(), "Parameter bytes is not declared");
+    }
+}
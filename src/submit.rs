@@ -0,0 +1,305 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use reqwest::header::{COOKIE, USER_AGENT};
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::fetch::AGENT;
+use crate::input;
+use crate::solution::{Part, Puzzle};
+
+/// What the server made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reply {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint about which way.
+    Wrong,
+    /// Submitted too soon after the last guess, with the wait as the server
+    /// words it, e.g. `34s`.
+    RateLimited(Option<String>),
+    /// The part is already solved, or part 1 is not solved yet.
+    WrongLevel,
+    Unrecognized,
+}
+
+impl Reply {
+    pub fn parse(body: &str) -> Self {
+        if body.contains("That's the right answer") {
+            Reply::Correct
+        } else if body.contains("your answer is too high") {
+            Reply::TooHigh
+        } else if body.contains("your answer is too low") {
+            Reply::TooLow
+        } else if body.contains("That's not the right answer") {
+            Reply::Wrong
+        } else if body.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have (.+?) left to wait").expect("Valid regex");
+            Reply::RateLimited(wait.captures(body).map(|captures| captures[1].to_string()))
+        } else if body.contains("You don't seem to be solving the right level") {
+            Reply::WrongLevel
+        } else {
+            Reply::Unrecognized
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Reply::TooHigh | Reply::TooLow | Reply::Wrong)
+    }
+}
+
+impl fmt::Display for Reply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reply::Correct => write!(f, "That's the right answer"),
+            Reply::TooHigh => write!(f, "Wrong, too high"),
+            Reply::TooLow => write!(f, "Wrong, too low"),
+            Reply::Wrong => write!(f, "Wrong"),
+            Reply::RateLimited(Some(wait)) => write!(f, "Rate limited, try again in {}", wait),
+            Reply::RateLimited(None) => write!(f, "Rate limited, try again later"),
+            Reply::WrongLevel => write!(f, "Not the right level, the part is solved already or still locked"),
+            Reply::Unrecognized => write!(f, "Unrecognized reply from the server"),
+        }
+    }
+}
+
+/// One submitted answer, as recorded in `inputs/YYYY/dayN/guesses.jsonl`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Guess {
+    pub part: u8,
+    pub answer: String,
+    pub reply: Reply,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+}
+
+pub fn log_path(puzzle: Puzzle) -> PathBuf {
+    input::dir(puzzle).join("guesses.jsonl")
+}
+
+pub fn load_guesses(puzzle: Puzzle) -> Result<Vec<Guess>> {
+    let path = log_path(puzzle);
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err).with_context(|| format!("Failed to read {}", path.display())),
+    };
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line).with_context(|| format!("Invalid guess on line {} of {}", index + 1, path.display()))
+        })
+        .collect()
+}
+
+fn record(puzzle: Puzzle, guess: &Guess) -> Result<()> {
+    let path = log_path(puzzle);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(guess)?).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Refuses answers the recorded guesses already rule out: a repeat of a wrong
+/// answer, one on the wrong side of a too high or too low guess, or any
+/// answer to a part that is already solved.
+pub fn check(guesses: &[Guess], part: Part, answer: &str) -> Result<()> {
+    let guesses: Vec<&Guess> = guesses.iter().filter(|guess| guess.part == part.number()).collect();
+
+    if let Some(correct) = guesses.iter().find(|guess| guess.reply == Reply::Correct) {
+        bail!("{} is already solved with {}", part, correct.answer);
+    }
+
+    if let Some(known) = guesses.iter().find(|guess| guess.answer == answer && guess.reply.is_wrong()) {
+        bail!("{} was already submitted and was wrong ({})", answer, known.reply);
+    }
+
+    let Ok(value) = answer.parse::<i64>() else {
+        return Ok(());
+    };
+    let bound = |reply: Reply| {
+        guesses
+            .iter()
+            .filter(move |guess| guess.reply == reply)
+            .filter_map(|guess| guess.answer.parse::<i64>().ok())
+    };
+
+    if let Some(too_high) = bound(Reply::TooHigh).filter(|&high| value >= high).min() {
+        bail!("{} is not below {}, which was already too high", answer, too_high);
+    }
+    if let Some(too_low) = bound(Reply::TooLow).filter(|&low| value <= low).max() {
+        bail!("{} is not above {}, which was already too low", answer, too_low);
+    }
+
+    Ok(())
+}
+
+async fn post(config: &Config, puzzle: Puzzle, part: Part, answer: &str) -> Result<String> {
+    let url = format!("{}/{}/day/{}/answer", config.base_url(), puzzle.year, puzzle.day);
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .header(USER_AGENT, AGENT)
+        .header(COOKIE, format!("session={}", config.session()?))
+        .form(&[("level", part.number().to_string()), ("answer", answer.to_string())])
+        .send()
+        .await
+        .with_context(|| format!("Failed to post to {}", url))?
+        .error_for_status()
+        .with_context(|| format!("{} rejected the answer", url))?;
+
+    response.text().await.with_context(|| format!("Failed to read the response from {}", url))
+}
+
+/// Submits `answer` for one part of `puzzle` unless the recorded guesses rule
+/// it out, and records the server's reply.
+pub fn submit(config: &Config, puzzle: Puzzle, part: Part, answer: &str) -> Result<Reply> {
+    let guesses = load_guesses(puzzle)?;
+    check(&guesses, part, answer).context("Not submitting")?;
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .context("Failed to start the async runtime")?;
+    let body = runtime.block_on(post(config, puzzle, part, answer))?;
+    let reply = Reply::parse(&body);
+
+    let time = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs());
+    let path = log_path(puzzle);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    record(puzzle, &Guess { part: part.number(), answer: answer.to_string(), reply: reply.clone(), time })?;
+
+    Ok(reply)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(part: u8, answer: &str, reply: Reply) -> Guess {
+        Guess { part, answer: answer.to_string(), reply, time: 0 }
+    }
+
+    fn article(text: &str) -> String {
+        format!("<main>\n<article><p>{}</p></article>\n</main>", text)
+    }
+
+    #[test]
+    fn parses_replies() {
+        let cases = [
+            (
+                "That's the right answer!  You are one gold star closer to finding the Chief Historian. \
+                 <a href=\"/2024/day/5#part2\">[Continue to Part Two]</a>",
+                Reply::Correct,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  If you're stuck, make sure you're \
+                 using the full input data.  Please wait one minute before trying again. \
+                 <a href=\"/2024/day/5\">[Return to Day 5]</a>",
+                Reply::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.  Please wait one minute before trying \
+                 again. <a href=\"/2024/day/5\">[Return to Day 5]</a>",
+                Reply::TooLow,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full input data; \
+                 there are also some general tips on the <a href=\"/2024/about\">about page</a>.",
+                Reply::Wrong,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying \
+                 again.  You have 34s left to wait. <a href=\"/2024/day/5\">[Return to Day 5]</a>",
+                Reply::RateLimited(Some(String::from("34s"))),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying \
+                 again. <a href=\"/2024/day/5\">[Return to Day 5]</a>",
+                Reply::RateLimited(None),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it? \
+                 <a href=\"/2024/day/5\">[Return to Day 5]</a>",
+                Reply::WrongLevel,
+            ),
+        ];
+
+        for (text, reply) in cases {
+            assert_eq!(Reply::parse(&article(text)), reply, "{}", text);
+        }
+        assert_eq!(Reply::parse("<html><body>Please log in</body></html>"), Reply::Unrecognized);
+    }
+
+    #[test]
+    fn allows_a_first_guess() {
+        assert!(check(&[], Part::One, "42").is_ok());
+    }
+
+    #[test]
+    fn refuses_a_solved_part() {
+        let guesses = [guess(1, "42", Reply::Correct)];
+        let err = check(&guesses, Part::One, "43").unwrap_err();
+        assert_eq!(err.to_string(), "part1 is already solved with 42");
+        assert!(check(&guesses, Part::Two, "43").is_ok());
+    }
+
+    #[test]
+    fn refuses_a_repeated_wrong_answer() {
+        let guesses = [guess(1, "abc", Reply::Wrong)];
+        assert!(check(&guesses, Part::One, "abc").is_err());
+        assert!(check(&guesses, Part::One, "abd").is_ok());
+    }
+
+    #[test]
+    fn allows_repeating_a_rate_limited_answer() {
+        let guesses = [guess(1, "42", Reply::RateLimited(None)), guess(1, "42", Reply::WrongLevel)];
+        assert!(check(&guesses, Part::One, "42").is_ok());
+    }
+
+    #[test]
+    fn refuses_answers_outside_the_bounds() {
+        let guesses = [
+            guess(1, "100", Reply::TooHigh),
+            guess(1, "200", Reply::TooHigh),
+            guess(1, "10", Reply::TooLow),
+            guess(1, "20", Reply::TooLow),
+            guess(2, "50", Reply::TooHigh),
+        ];
+
+        let err = check(&guesses, Part::One, "150").unwrap_err();
+        assert_eq!(err.to_string(), "150 is not below 100, which was already too high");
+        assert!(check(&guesses, Part::One, "100").is_err());
+        let err = check(&guesses, Part::One, "15").unwrap_err();
+        assert_eq!(err.to_string(), "15 is not above 20, which was already too low");
+        assert!(check(&guesses, Part::One, "20").is_err());
+
+        assert!(check(&guesses, Part::One, "21").is_ok());
+        assert!(check(&guesses, Part::One, "99").is_ok());
+
+        // Each part only has its own bounds.
+        assert!(check(&guesses, Part::Two, "5").is_ok());
+        assert!(check(&guesses, Part::Two, "60").is_err());
+    }
+
+    #[test]
+    fn ignores_bounds_for_text_answers() {
+        let guesses = [guess(1, "100", Reply::TooHigh)];
+        assert!(check(&guesses, Part::One, "1,2,3").is_ok());
+    }
+}
@@ -0,0 +1,32 @@
+/// Prints rows as a left-aligned plain-text table under `headers`.
+pub fn print(headers: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = headers.iter().map(|header| header.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    };
+
+    let headers: Vec<String> = headers.iter().map(|header| header.to_string()).collect();
+    println!("{}", format_row(&headers));
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|&width| "-".repeat(width))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+    for row in rows {
+        println!("{}", format_row(row));
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+
+use crate::answers::Answers;
+use crate::examples::Example;
+use crate::input::InputSource;
+use crate::runner::Outcome;
+use crate::solution::{Answer, Puzzle, Task};
+use crate::table;
+
+pub enum Verdict {
+    Match,
+    Mismatch { expected: String, actual: String },
+    Unknown,
+    Failed(String),
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Mismatch { .. } | Verdict::Failed(_))
+    }
+}
+
+fn load_answers(puzzle: Puzzle, source: &InputSource) -> Result<Answers> {
+    match source {
+        InputSource::Example(name) => Ok(Example::load(puzzle, name)?.answers),
+        _ => Answers::load(puzzle),
+    }
+}
+
+/// Compares each outcome with the stored answer for its day and task, taken
+/// from the example's entry in `examples.toml` when running an example.
+pub fn check(outcomes: &[Outcome], source: &InputSource) -> Vec<Verdict> {
+    let mut answers = BTreeMap::new();
+
+    outcomes
+        .iter()
+        .map(|outcome| {
+            let answers = answers
+                .entry(outcome.puzzle)
+                .or_insert_with(|| load_answers(outcome.puzzle, source).map_err(|err| format!("{:#}", err)));
+
+            let answers = match answers {
+                Ok(answers) => answers,
+                Err(err) => return Verdict::Failed(err.clone()),
+            };
+
+            verdict(answers, outcome.task, &outcome.answer)
+        })
+        .collect()
+}
+
+/// Compares the answer `task` produced with its stored answer, if any.
+fn verdict(answers: &Answers, task: Task, answer: &Result<Answer>) -> Verdict {
+    let actual = match answer {
+        Ok(answer) => answer.to_string(),
+        Err(err) => return Verdict::Failed(format!("{:#}", err)),
+    };
+
+    match answers.get(task) {
+        None => Verdict::Unknown,
+        Some(expected) if expected == actual => Verdict::Match,
+        Some(expected) => Verdict::Mismatch { expected: expected.to_string(), actual },
+    }
+}
+
+pub fn print_report(outcomes: &[Outcome], verdicts: &[Verdict]) {
+    let rows: Vec<Vec<String>> = outcomes
+        .iter()
+        .zip(verdicts)
+        .map(|(outcome, verdict)| {
+            let (status, detail) = match verdict {
+                Verdict::Match => ("match", outcome.answer.as_ref().map(|a| a.to_string()).unwrap_or_default()),
+                Verdict::Mismatch { expected, actual } => {
+                    ("MISMATCH", format!("expected {}, got {}", expected, actual))
+                }
+                Verdict::Unknown => (
+                    "unknown",
+                    outcome.answer.as_ref().map(|a| a.to_string()).unwrap_or_default(),
+                ),
+                Verdict::Failed(err) => ("ERROR", err.clone()),
+            };
+
+            vec![
+                outcome.puzzle.year.to_string(),
+                outcome.puzzle.day.to_string(),
+                outcome.task.part.number().to_string(),
+                outcome.task.variant.unwrap_or("-").to_string(),
+                status.to_string(),
+                detail,
+            ]
+        })
+        .collect();
+
+    table::print(&["Year", "Day", "Part", "Variant", "Result", "Details"], &rows);
+
+    let count = |f: fn(&Verdict) -> bool| verdicts.iter().filter(|v| f(v)).count();
+    println!();
+    println!(
+        "{} match, {} mismatch, {} unknown, {} failed",
+        count(|v| matches!(v, Verdict::Match)),
+        count(|v| matches!(v, Verdict::Mismatch { .. })),
+        count(|v| matches!(v, Verdict::Unknown)),
+        count(|v| matches!(v, Verdict::Failed(_))),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+    use crate::solution::Part;
+
+    fn answers() -> Answers {
+        Answers::parse("part1 = 2378066\npart2 = \"6,1\"\npart1_optimized = 2378066").unwrap()
+    }
+
+    #[test]
+    fn matches_stored_answers() {
+        let task = Task::new(Part::One, None);
+        assert!(matches!(verdict(&answers(), task, &Ok(Answer::from(2378066i64))), Verdict::Match));
+        let task = Task::new(Part::Two, None);
+        assert!(matches!(verdict(&answers(), task, &Ok(Answer::from(String::from("6,1")))), Verdict::Match));
+    }
+
+    #[test]
+    fn compares_variants_with_their_own_answer() {
+        let task = Task::new(Part::One, Some("optimized"));
+        assert!(matches!(verdict(&answers(), task, &Ok(Answer::from(2378066u64))), Verdict::Match));
+    }
+
+    #[test]
+    fn reports_mismatches() {
+        let task = Task::new(Part::One, None);
+        match verdict(&answers(), task, &Ok(Answer::from(42i64))) {
+            Verdict::Mismatch { expected, actual } => {
+                assert_eq!((expected.as_str(), actual.as_str()), ("2378066", "42"));
+            }
+            _ => panic!("Expected a mismatch"),
+        }
+    }
+
+    #[test]
+    fn knows_nothing_about_tasks_without_an_answer() {
+        let task = Task::new(Part::Two, Some("optimized"));
+        assert!(matches!(verdict(&answers(), task, &Ok(Answer::from(1i64))), Verdict::Unknown));
+        assert!(matches!(verdict(&Answers::default(), task, &Ok(Answer::from(1i64))), Verdict::Unknown));
+    }
+
+    #[test]
+    fn fails_tasks_that_failed() {
+        let task = Task::new(Part::One, None);
+        match verdict(&answers(), task, &Err(anyhow!("No path to the exit"))) {
+            Verdict::Failed(err) => assert_eq!(err, "No path to the exit"),
+            _ => panic!("Expected a failure"),
+        }
+    }
+}
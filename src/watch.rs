@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::input::{self, InputSource};
+use crate::registry::DynSolution;
+use crate::runner::{self, with_silent_panics};
+use crate::solution::{Params, Task};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Modification times of the files directly in `dir`. A missing directory
+/// has no files rather than being an error, so it can be created later.
+fn snapshot(dir: &Path) -> BTreeMap<PathBuf, SystemTime> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return BTreeMap::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok().filter(|metadata| metadata.is_file())?;
+            Some((entry.path(), metadata.modified().ok()?))
+        })
+        .collect()
+}
+
+/// Runs `tasks` and then again whenever a file in the puzzle's input
+/// directory, or the `--input` file, changes, until the process is
+/// interrupted. Failures, including parse errors, are shown in the results
+/// and the watch carries on.
+pub fn watch(solution: &dyn DynSolution, source: &InputSource, overrides: &Params, tasks: &[Task]) -> ! {
+    let dir = input::dir(solution.puzzle());
+    let file = match source {
+        InputSource::File(path) => Some(path),
+        _ => None,
+    };
+    let watched = match file {
+        Some(path) => format!("{} and {}", dir.display(), path.display()),
+        None => dir.display().to_string(),
+    };
+    let mut seen = None;
+
+    loop {
+        let mut current = snapshot(&dir);
+        if let Some(path) = file {
+            // A file that is missing for now has no time, so it is picked up
+            // once it is written.
+            if let Ok(modified) = fs::metadata(path).and_then(|metadata| metadata.modified()) {
+                current.insert(path.clone(), modified);
+            }
+        }
+        if seen.as_ref() != Some(&current) {
+            let outcomes = with_silent_panics(|| runner::run_day(solution, source, overrides, tasks));
+
+            print!("{}", CLEAR_SCREEN);
+            println!("Watching {} for changes to {}, Ctrl-C to stop", solution.puzzle(), watched);
+            println!();
+            runner::print_logs(&outcomes);
+            runner::print_table(&outcomes);
+
+            seen = Some(current);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
@@ -0,0 +1,25 @@
+use crate::registry::Registry;
+
+macro_rules! days {
+    ($($day:ident => $solution:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        pub fn register(registry: &mut crate::registry::Registry) {
+            $(registry.add($day::$solution);)*
+        }
+    };
+}
+
+macro_rules! years {
+    ($($year:ident),* $(,)?) => {
+        $(pub mod $year;)*
+
+        pub fn register(registry: &mut Registry) {
+            $($year::register(registry);)*
+        }
+    };
+}
+
+years! {
+    y2024,
+}
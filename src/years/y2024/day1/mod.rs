@@ -0,0 +1,29 @@
+pub mod utils;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::solution::{Answer, Params, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<i32>, Vec<i32>);
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 1;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,18 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+
+pub fn solve(input: &(Vec<i32>, Vec<i32>)) -> Result<Answer> {
+    let (mut list1, mut list2) = input.clone();
+
+    list1.sort_unstable();
+    list2.sort_unstable();
+
+    let total_difference: i32 = list1
+        .iter()
+        .zip(list2.iter())
+        .map(|(a, b)| (a - b).abs())
+        .sum();
+
+    Ok(total_difference.into())
+}
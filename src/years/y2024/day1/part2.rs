@@ -0,0 +1,21 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::solution::Answer;
+
+pub fn solve(input: &(Vec<i32>, Vec<i32>)) -> Result<Answer> {
+    let (list1, list2) = input;
+
+    let mut occurrences = HashMap::new();
+    for &num in list2 {
+        *occurrences.entry(num).or_insert(0) += 1;
+    }
+
+    let similarity_score: i32 = list1
+        .iter()
+        .map(|&num| num * occurrences.get(&num).unwrap_or(&0))
+        .sum();
+
+    Ok(similarity_score.into())
+}
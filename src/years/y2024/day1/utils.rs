@@ -0,0 +1,17 @@
+use crate::error::{parse_at, Error};
+
+pub fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), Error> {
+    let mut list1 = Vec::new();
+    let mut list2 = Vec::new();
+
+    for line in input.lines() {
+        let mut numbers = line.split_whitespace();
+        let (Some(first), Some(second)) = (numbers.next(), numbers.next()) else {
+            return Err(Error::missing(input, line, "Expected two numbers"));
+        };
+        list1.push(parse_at(input, first, "a number")?);
+        list2.push(parse_at(input, second, "a number")?);
+    }
+
+    Ok((list1, list2))
+}
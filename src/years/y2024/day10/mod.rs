@@ -0,0 +1,42 @@
+pub mod utils;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::shape;
+use crate::solution::{Answer, Params, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Vec<u8>>;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 10;
+
+    fn validate(&self, input: &str) -> Result<()> {
+        Ok(shape::rectangular(input, input)?)
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+
+    fn render(&self, input: &Self::Input) -> Option<String> {
+        let rows: Vec<String> = input
+            .iter()
+            .map(|row| row.iter().map(|&height| char::from(b'0' + height)).collect())
+            .collect();
+        Some(rows.join("\n"))
+    }
+}
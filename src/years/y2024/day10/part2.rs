@@ -0,0 +1,10 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::utils::find_trail_ratings;
+
+pub fn solve(grid: &[Vec<u8>]) -> Result<Answer> {
+    let trail_scores = find_trail_ratings(grid);
+
+    Ok(trail_scores.into())
+}
@@ -1,9 +1,9 @@
-use std::io;
-use std::fs;
 use std::collections::{HashSet, VecDeque};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
+use crate::error::Error;
+
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
 pub struct Coord {
     pub x: i32,
@@ -38,7 +38,7 @@ impl Coord {
         }
     }
 
-    pub fn next_in_trail(&self,grid: &Vec<Vec<u8>>, current_value: u8) -> Vec<Coord> {
+    pub fn next_in_trail(&self,grid: &[Vec<u8>], current_value: u8) -> Vec<Coord> {
         let mut next_coords = Vec::new();
 
         for direction in Direction::iter() {
@@ -52,24 +52,31 @@ impl Coord {
         next_coords
     }
 
-    pub fn is_within_bounds(&self, grid: &Vec<Vec<u8>>) -> bool {
+    pub fn is_within_bounds(&self, grid: &[Vec<u8>]) -> bool {
         self.x >= 0 && self.y >= 0 && self.x < grid.len() as i32 && self.y < grid[0].len() as i32
     }
 }
 
 
-pub fn read_input() -> io::Result<Vec<Vec<u8>>> {
-    let input = fs::read_to_string("inputs/day10/input.txt")?;
+pub fn parse_input(input: &str) -> Result<Vec<Vec<u8>>, Error> {
     let mut grid = Vec::new();
 
     for line in input.lines() {
-        grid.push(line.chars().map(|c| c.to_digit(10).unwrap() as u8).collect());
+        let row = line
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .map(|height| height as u8)
+                    .ok_or_else(|| Error::parse(input, &line[i..i + c.len_utf8()], "Expected a height from 0 to 9"))
+            })
+            .collect::<Result<_, _>>()?;
+        grid.push(row);
     }
 
     Ok(grid)
 }
 
-fn bfs_score(grid: &Vec<Vec<u8>>, start: Coord) -> usize {
+fn bfs_score(grid: &[Vec<u8>], start: Coord) -> usize {
     let mut queue = VecDeque::new();
     let mut visited = HashSet::new();
     let mut reachable_nines = 0;
@@ -94,7 +101,7 @@ fn bfs_score(grid: &Vec<Vec<u8>>, start: Coord) -> usize {
     reachable_nines
 }
 
-pub fn find_trail_heads(grid: &Vec<Vec<u8>>) -> Vec<Coord> {
+pub fn find_trail_heads(grid: &[Vec<u8>]) -> Vec<Coord> {
     let mut trail_heads = Vec::new();
 
     for (x, row) in grid.iter().enumerate() {
@@ -108,27 +115,27 @@ pub fn find_trail_heads(grid: &Vec<Vec<u8>>) -> Vec<Coord> {
         }
     }
 
-    return trail_heads
+    trail_heads
 }
 
 
-pub fn find_trail_scores(grid: &Vec<Vec<u8>>) -> usize {
+pub fn find_trail_scores(grid: &[Vec<u8>]) -> usize {
     
     let trail_heads = find_trail_heads(grid);
     let mut results = Vec::new();
     let mut score_sums: usize = 0;
     for head in &trail_heads {
-        let score = bfs_score(&grid, *head);
+        let score = bfs_score(grid, *head);
         results.push((head, score));
         score_sums += score;
     }
 
-    return score_sums;
+    score_sums
 }
 
-fn count_unique_paths(grid: &Vec<Vec<u8>>, start: Coord) -> usize {
+fn count_unique_paths(grid: &[Vec<u8>], start: Coord) -> usize {
     fn dfs(
-        grid: &Vec<Vec<u8>>, 
+        grid: &[Vec<u8>], 
         coord: Coord, 
         visited: &mut HashSet<Coord>, 
         current_value: u8,
@@ -154,16 +161,16 @@ fn count_unique_paths(grid: &Vec<Vec<u8>>, start: Coord) -> usize {
     dfs(grid, start, &mut visited, 0)
 }
 
-pub fn find_trail_ratings(grid: &Vec<Vec<u8>>) -> usize {
+pub fn find_trail_ratings(grid: &[Vec<u8>]) -> usize {
     
     let trail_heads = find_trail_heads(grid);
     let mut results = Vec::new();
     let mut rating_sums: usize = 0;
     for head in &trail_heads {
-        let rating = count_unique_paths(&grid, *head);
+        let rating = count_unique_paths(grid, *head);
         results.push((head, rating));
         rating_sums += rating;
     }
 
-    return rating_sums;
+    rating_sums
 }
\ No newline at end of file
@@ -0,0 +1,38 @@
+pub mod utils;
+/// Both parts, which differ only in their parameters.
+pub mod solve;
+
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::solution::{Answer, Param, Params, Part, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = HashMap<u64, u64>;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 11;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input, params: &Params) -> Result<Answer> {
+        solve::solve(input, params)
+    }
+
+    fn part2(&self, input: &Self::Input, params: &Params) -> Result<Answer> {
+        solve::solve(input, params)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param { name: "blinks", part: Some(Part::One), default: 25, description: "Times the stones blink" },
+            Param { name: "blinks", part: Some(Part::Two), default: 75, description: "Times the stones blink" },
+        ]
+    }
+}
@@ -0,0 +1,12 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::solution::{Answer, Params};
+use super::utils::blink;
+
+pub fn solve(input: &HashMap<u64, u64>, params: &Params) -> Result<Answer> {
+    let output = blink(input.clone(), params.get("blinks")?);
+
+    Ok(output.into())
+}
@@ -1,14 +1,14 @@
-use std::i32;
-use std::io;
-use std::fs;
 use std::collections::HashMap;
 use std::time::Instant;
+use crate::debug;
+use crate::error::{parse_at, Error};
 
-pub fn read_input() -> io::Result<HashMap<u64, u64>> {
-    let input = fs::read_to_string("inputs/day11/input.txt")?;
+pub fn parse_input(input: &str) -> Result<HashMap<u64, u64>, Error> {
     let mut stones: HashMap<u64, u64> = HashMap::new();
 
-    input.split_whitespace().for_each(|s| *stones.entry(s.parse().unwrap()).or_default() += 1);    
+    for s in input.split_whitespace() {
+        *stones.entry(parse_at(input, s, "a number")?).or_default() += 1;
+    }
 
     Ok(stones)
 }
@@ -16,7 +16,7 @@ pub fn read_input() -> io::Result<HashMap<u64, u64>> {
 fn split_number(num: u64) -> Option<(u64, u64)> {
     let len = num.ilog10() + 1;
 
-    if len % 2 != 0 {
+    if !len.is_multiple_of(2) {
         return None;
     }
 
@@ -43,7 +43,7 @@ pub fn blink(input: HashMap<u64, u64>, times: i32) -> u64 {
         }
         current = updated;
         let duration = start.elapsed();
-        println!("Blink: {}. Stones: {}. Time taken: {:.2?}", k, current.values().sum::<u64>(), duration);
+        debug!("Blink: {}. Stones: {}. Time taken: {:.2?}", k, current.values().sum::<u64>(), duration);
     }
 
     current.values().sum::<u64>()
@@ -0,0 +1,39 @@
+pub mod utils;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::shape;
+use crate::solution::{Answer, Params, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Vec<char>>;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 12;
+
+    fn validate(&self, input: &str) -> Result<()> {
+        Ok(shape::rectangular(input, input)?)
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input))
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+
+    fn render(&self, input: &Self::Input) -> Option<String> {
+        let rows: Vec<String> = input.iter().map(|row| row.iter().collect()).collect();
+        Some(rows.join("\n"))
+    }
+}
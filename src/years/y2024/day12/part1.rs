@@ -0,0 +1,10 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::utils::cost;
+
+pub fn solve(grid: &[Vec<char>]) -> Result<Answer> {
+    let output = cost(grid);
+
+    Ok(output.into())
+}
@@ -0,0 +1,10 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::utils::bulk_cost;
+
+pub fn solve(grid: &[Vec<char>]) -> Result<Answer> {
+    let output = bulk_cost(grid);
+
+    Ok(output.into())
+}
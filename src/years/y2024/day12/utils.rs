@@ -1,6 +1,3 @@
-use std::i32;
-use std::io;
-use std::fs;
 use std::collections::HashSet;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
@@ -58,7 +55,7 @@ impl Coord {
         *self + direction.to_offset()
     }
 
-    pub fn is_within_bounds(&self, grid: &Vec<Vec<char>>) -> bool {
+    pub fn is_within_bounds(&self, grid: &[Vec<char>]) -> bool {
         self.x >= 0 && self.y >= 0 && self.x < grid.len() as i32 && self.y < grid[0].len() as i32
     }
 }
@@ -97,16 +94,15 @@ impl Add for Coord {
     }
 }
 
-pub fn read_input() -> io::Result<Vec<Vec<char>>> {
-    let input = fs::read_to_string("inputs/day12/input.txt")?;
+pub fn parse_input(input: &str) -> Vec<Vec<char>> {
     let grid = input
         .lines()
         .map(|line| line.chars().collect::<Vec<char>>())
         .collect::<Vec<Vec<char>>>();
-    Ok(grid)
+    grid
 }
 
-pub fn find_region(cell: &char, current_coord: Coord, grid: &Vec<Vec<char>>) -> (i32, i32, i32, HashSet<Coord>) {
+pub fn find_region(cell: &char, current_coord: Coord, grid: &[Vec<char>]) -> (i32, i32, i32, HashSet<Coord>) {
     let mut stack = vec![current_coord];
     let mut visited: HashSet<Coord> = HashSet::new();
     let mut boundary_edges = 0;
@@ -134,7 +130,7 @@ pub fn find_region(cell: &char, current_coord: Coord, grid: &Vec<Vec<char>>) ->
     (visited.len() as i32, boundary_edges, corners, visited)
 }
 
-pub fn find_corners(cell: &char, current_coord: Coord, grid: &Vec<Vec<char>>) -> i32 {
+pub fn find_corners(cell: &char, current_coord: Coord, grid: &[Vec<char>]) -> i32 {
     CORNERS_OFFSET
             .iter()
             .filter(|corner| {
@@ -153,7 +149,7 @@ pub fn find_corners(cell: &char, current_coord: Coord, grid: &Vec<Vec<char>>) ->
 
 }
 
-pub fn build_regions(grid: Vec<Vec<char>>) -> Vec<Region> {
+pub fn build_regions(grid: &[Vec<char>]) -> Vec<Region> {
     let mut regions = Vec::new();
     let mut visited: HashSet<Coord> = HashSet::new();
     for (i, row) in grid.iter().enumerate() {
@@ -162,7 +158,7 @@ pub fn build_regions(grid: Vec<Vec<char>>) -> Vec<Region> {
             if visited.contains(&current_coord) {
                 continue;
             }
-            let (new_area, new_perimeter, new_corners, new_visited) = find_region(&cell, current_coord, &grid);
+            let (new_area, new_perimeter, new_corners, new_visited) = find_region(&cell, current_coord, grid);
 
             regions.push(Region {
                 id: cell,
@@ -177,12 +173,12 @@ pub fn build_regions(grid: Vec<Vec<char>>) -> Vec<Region> {
     regions
 }
 
-pub fn cost(grid: Vec<Vec<char>>) -> i32 {
+pub fn cost(grid: &[Vec<char>]) -> i32 {
     let regions = build_regions(grid);
     regions.iter().map(|region| region.cost()).sum::<i32>()
 }
 
-pub fn bulk_cost(grid: Vec<Vec<char>>) -> i32 {
+pub fn bulk_cost(grid: &[Vec<char>]) -> i32 {
     let regions = build_regions(grid);
     regions.iter().map(|region| region.bulk_cost()).sum::<i32>()
 }
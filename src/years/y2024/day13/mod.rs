@@ -0,0 +1,29 @@
+pub mod utils;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::solution::{Answer, Params, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<utils::Input>;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 13;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
@@ -0,0 +1,15 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::utils::{solve_buttons, Input};
+
+pub fn solve(inputs: &[Input]) -> Result<Answer> {
+    let mut tokens = 0;
+    for input in inputs {
+        if let Some(solution) = solve_buttons(&input.with_increased_prize(10000000000000)) {
+            tokens += solution.0 * 3 + solution.1;
+        }
+    }
+
+    Ok(tokens.into())
+}
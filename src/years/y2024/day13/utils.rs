@@ -1,6 +1,6 @@
-use std::io;
-use std::fs;
-use regex::Regex;
+use regex::{Captures, Regex};
+
+use crate::error::{parse_at, Error};
 
 #[derive(Debug)]
 pub struct Input {
@@ -55,14 +55,17 @@ pub fn solve_buttons(input: &Input) -> Option<(i64, i64)> {
 
 
 
-pub fn read_input() -> io::Result<Vec<Input>> {
-    let input = fs::read_to_string("inputs/day13/input.txt")?;
+pub fn parse_input(input: &str) -> Result<Vec<Input>, Error> {
     let mut inputs: Vec<Input> = Vec::new();
     
     let button_a_re = Regex::new(r"Button A: X([+-]\d+), Y([+-]\d+)").unwrap();
     let button_b_re = Regex::new(r"Button B: X([+-]\d+), Y([+-]\d+)").unwrap();
     let prize_re = Regex::new(r"Prize: X=(\d+), Y=(\d+)").unwrap();
 
+    let pair = |caps: &Captures| -> Result<(i64, i64), Error> {
+        Ok((parse_at(input, &caps[1], "a number")?, parse_at(input, &caps[2], "a number")?))
+    };
+
     for chunk in input.split("\n\n") {
         let mut a = (0, 0);
         let mut b = (0, 0);
@@ -70,20 +73,13 @@ pub fn read_input() -> io::Result<Vec<Input>> {
 
         for line in chunk.lines() {
             if let Some(caps) = button_a_re.captures(line) {
-                a = (
-                    caps[1].parse().unwrap(),
-                    caps[2].parse().unwrap()
-                );
+                a = pair(&caps)?;
             } else if let Some(caps) = button_b_re.captures(line) {
-                b = (
-                    caps[1].parse().unwrap(),
-                    caps[2].parse().unwrap()
-                );
+                b = pair(&caps)?;
             } else if let Some(caps) = prize_re.captures(line) {
-                prize = (
-                    caps[1].parse().unwrap(),
-                    caps[2].parse().unwrap()
-                );
+                prize = pair(&caps)?;
+            } else if !line.is_empty() {
+                return Err(Error::parse(input, line, "Expected a button or a prize"));
             }
         }
 
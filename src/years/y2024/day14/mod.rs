@@ -0,0 +1,42 @@
+pub mod utils;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::solution::{Answer, Param, Params, Part, Simulation, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<utils::Robot>;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 14;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input, params: &Params) -> Result<Answer> {
+        part1::solve(input, params)
+    }
+
+    fn part2(&self, input: &Self::Input, params: &Params) -> Result<Answer> {
+        part2::solve(input, params)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param { name: "width", part: None, default: 101, description: "Width of the space the robots move in" },
+            Param { name: "height", part: None, default: 103, description: "Height of the space the robots move in" },
+            Param { name: "ticks", part: Some(Part::One), default: 100, description: "Seconds to simulate before the safety factor" },
+            Param { name: "max_ticks", part: Some(Part::Two), default: 10000, description: "Seconds to search for the tree before giving up" },
+        ]
+    }
+
+    fn simulate(&self, input: &Self::Input, params: &Params) -> Result<Box<dyn Simulation>> {
+        Ok(Box::new(utils::Grid::new(params.get("width")?, params.get("height")?, input.clone())))
+    }
+}
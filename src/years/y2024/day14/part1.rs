@@ -0,0 +1,16 @@
+use anyhow::Result;
+
+use crate::solution::{Answer, Params};
+use super::utils::{Grid, Robot};
+
+pub fn solve(inputs: &[Robot], params: &Params) -> Result<Answer> {
+    let width = params.get("width")?;
+    let height = params.get("height")?;
+    let mut grid = Grid::new(width, height, inputs.to_vec());
+
+    for _ in 0..params.get::<i32>("ticks")? {
+        grid.tick();
+    }
+
+    Ok(grid.safety_factor().into())
+}
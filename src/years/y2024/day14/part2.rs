@@ -0,0 +1,23 @@
+use anyhow::Result;
+
+use crate::debug;
+use crate::error::Error;
+use crate::solution::{Answer, Params};
+use super::utils::{Grid, Robot};
+
+pub fn solve(inputs: &[Robot], params: &Params) -> Result<Answer> {
+    let width = params.get("width")?;
+    let height = params.get("height")?;
+    let max_ticks: i32 = params.get("max_ticks")?;
+    let mut grid = Grid::new(width, height, inputs.to_vec());
+
+    for t in 0..max_ticks {
+        grid.tick();
+        if grid.is_unique_locations() {
+            debug!("Robots after {} ticks:\n{}", t + 1, grid.grid_for_print());
+            return Ok((t + 1).into());
+        }
+    }
+    
+    Err(Error::NoSolution(format!("No tick with unique robot locations within {} ticks", max_ticks)).into())
+}
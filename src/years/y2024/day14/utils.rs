@@ -1,15 +1,10 @@
-use std::io::{self, Write};
-use std::fs;
+use anyhow::Result;
 use regex::Regex;
-use strum_macros::EnumIter;
 use std::collections::{HashMap, HashSet};
-use clust::Client;
-use clust::messages::ClaudeModel;
-use clust::messages::MaxTokens;
-use clust::messages::MessagesRequestBody;
-use clust::messages::SystemPrompt;
-use clust::messages::Message;
-use anyhow::{Context, Result};
+
+use crate::error::{parse_at, Error};
+use crate::solution::Simulation;
+use crate::trace;
 
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
@@ -18,13 +13,6 @@ pub struct Coord {
     pub y: i32,
 }
 
-#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy, EnumIter)]
-pub enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
 pub struct Robot {
     coord: Coord,
@@ -65,7 +53,7 @@ impl Grid {
         let mut grid: Vec<Vec<String>> = vec![vec![String::from("."); self.width as usize]; self.height as usize];
 
         for robot in self.robots.iter() {
-            if grid[robot.coord.y as usize][robot.coord.x as usize] ==String::from(".") {
+            if grid[robot.coord.y as usize][robot.coord.x as usize] == "." {
                 grid[robot.coord.y as usize][robot.coord.x as usize] = String::from("1");
             } else {
                 grid[robot.coord.y as usize][robot.coord.x as usize] = 
@@ -80,13 +68,7 @@ impl Grid {
         rows.join("\n")
     }
 
-    pub fn print(&self) {
-        println!("{}", self.grid_for_print());
-
-        println!()
-    }
-
-    pub fn tick(&mut self, print: bool) {
+    pub fn tick(&mut self) {
         for robot in self.robots.iter_mut() {
             robot.coord.x += robot.velocity.x;
             robot.coord.x = Grid::wrap(robot.coord.x, self.width);
@@ -95,9 +77,7 @@ impl Grid {
             robot.coord.y = Grid::wrap(robot.coord.y, self.height);
         }
 
-        if print {
-            self.print();
-        }
+        trace!("Robots after a tick:\n{}", self.grid_for_print());
     }
 
     pub fn is_unique_locations(&mut self) -> bool {
@@ -121,26 +101,35 @@ impl Grid {
     }
 }
 
+impl Simulation for Grid {
+    fn step(&mut self) -> Result<String> {
+        self.tick();
+        Ok(format!("Safety factor {}", self.safety_factor()))
+    }
 
-pub fn read_input() -> io::Result<Vec<Robot>> {
-    let input = fs::read_to_string("inputs/day14/input.txt")?;
+    fn render(&self) -> String {
+        self.grid_for_print()
+    }
+}
 
+pub fn parse_input(input: &str) -> Result<Vec<Robot>, Error> {
     let re = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
 
-    let robots: Vec<Robot> = input.lines()
-        .filter_map(|line| re.captures(line))
-        .map(|caps| {
+    input.lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let caps = re
+                .captures(line)
+                .ok_or_else(|| Error::parse(input, line, "Expected a robot such as p=0,4 v=3,-3"))?;
             let coord = Coord {
-                x: caps[1].parse::<i32>().unwrap(),
-                y: caps[2].parse::<i32>().unwrap(),
+                x: parse_at(input, &caps[1], "a position")?,
+                y: parse_at(input, &caps[2], "a position")?,
             };
             let velocity = Coord {
-                x: caps[3].parse::<i32>().unwrap(),
-                y: caps[4].parse::<i32>().unwrap(),
+                x: parse_at(input, &caps[3], "a velocity")?,
+                y: parse_at(input, &caps[4], "a velocity")?,
             };
-            Robot { coord, velocity }
+            Ok(Robot { coord, velocity })
         })
-        .collect();
-
-    Ok(robots)
+        .collect()
 }
@@ -0,0 +1,47 @@
+pub mod utils;
+pub mod utils2;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::shape;
+use crate::solution::{Answer, Params, Simulation, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = utils::Input;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 15;
+
+    fn validate(&self, input: &str) -> Result<()> {
+        // The moves are checked by `parse`.
+        let map = input.split_once("\n\n").map_or(input, |(map, _)| map);
+        shape::rectangular(input, map)?;
+        shape::only(input, map, "#.O@")?;
+        Ok(shape::exactly_one(input, map, '@', "robot @")?)
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+
+    fn render(&self, input: &Self::Input) -> Option<String> {
+        Some(input.0.trim_end().to_string())
+    }
+
+    fn simulate(&self, input: &Self::Input, _params: &Params) -> Result<Box<dyn Simulation>> {
+        Ok(Box::new(utils::Warehouse::new(input)))
+    }
+}
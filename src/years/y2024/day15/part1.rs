@@ -0,0 +1,15 @@
+use anyhow::Result;
+
+use crate::debug;
+use crate::solution::Answer;
+use super::utils::{Grid, Input};
+
+pub fn solve(input: &Input) -> Result<Answer> {
+    let (map, commands) = input;
+    let mut grid = Grid::new(map.clone());
+
+    debug!("Initial map:\n{}", grid.render());
+    grid.process_commands(commands)?;
+
+    Ok(grid.gps().into())
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::utils::Input;
+use super::utils2::Grid;
+
+pub fn solve(input: &Input) -> Result<Answer> {
+    let (map, commands) = input;
+    let mut grid = Grid::new(map.clone());
+
+    grid.process_commands(commands)?;
+    
+    Ok(grid.gps().into())
+}
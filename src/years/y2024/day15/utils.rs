@@ -1,7 +1,10 @@
-use std::io;
-use std::fs;
+use anyhow::{anyhow, Result};
 use strum_macros::EnumIter;
 
+use crate::error::Error;
+use crate::solution::Simulation;
+use crate::trace;
+
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
 pub struct Coord {
     pub x: i32,
@@ -17,7 +20,7 @@ pub enum Direction {
 }
 
 impl Direction {
-    pub fn to_offset(&self) -> (i32, i32) {
+    pub fn to_offset(self) -> (i32, i32) {
         match self {
             Direction::North => (-1, 0),
             Direction::East => (0, 1),
@@ -92,27 +95,29 @@ impl Grid {
         }
     }
 
-    pub fn print_grid(&self) {
-        for row in &self.grid {
-            println!("{}", row.iter().map(|&e| char::from(e)).collect::<String>());
-        }
-
-        println!()
+    pub fn render(&self) -> String {
+        let rows: Vec<String> = self
+            .grid
+            .iter()
+            .map(|row| row.iter().map(|&e| char::from(e)).collect())
+            .collect();
+        rows.join("\n")
     }
 
-    pub fn move_robot_char(&mut self, command: char) {
+    pub fn move_robot_char(&mut self, command: char) -> Result<(), Error> {
         match command {
             '^' => self.move_robot(Direction::North),
             '>' => self.move_robot(Direction::East),
             'v' => self.move_robot(Direction::South),
             '<' => self.move_robot(Direction::West),
-            _ => panic!("Invalid command"),
+            _ => return Err(Error::InvalidState(format!("Unknown move {:?}", command))),
         }
+        Ok(())
     }
 
     pub fn move_robot(&mut self, direction: Direction) {
         let offset = direction.to_offset();
-        let (new_x, new_y) = (self.robot.coord.x as i32 + offset.0, self.robot.coord.y as i32 + offset.1);
+        let (new_x, new_y) = (self.robot.coord.x + offset.0, self.robot.coord.y + offset.1);
 
         if self.grid[new_x as usize][new_y as usize] == Element::Wall {
             return;
@@ -148,12 +153,12 @@ impl Grid {
         }
     }
 
-    pub fn process_commands(&mut self, commands: Vec<char>) {
-        for c in commands {
-            println!("Command: {}", c);
-            self.move_robot_char(c);
-            self.print_grid();
+    pub fn process_commands(&mut self, commands: &[char]) -> Result<(), Error> {
+        for &c in commands {
+            self.move_robot_char(c)?;
+            trace!("After command {}:\n{}", c, self.render());
         }
+        Ok(())
     }
 
     pub fn gps(&self) -> i32 {
@@ -170,16 +175,60 @@ impl Grid {
     }
 }
 
-pub fn read_input() -> io::Result<(Grid, Vec<char>)> {
-    let input = fs::read_to_string("inputs/day15/input.txt")?;
+/// Part 1's warehouse with the robot's commands, run one command per step.
+pub struct Warehouse {
+    grid: Grid,
+    commands: Vec<char>,
+    next: usize,
+}
+
+impl Warehouse {
+    pub fn new((map, commands): &Input) -> Self {
+        Warehouse { grid: Grid::new(map.clone()), commands: commands.clone(), next: 0 }
+    }
+}
 
-    let mut sections = input.split("\n\n");
+impl Simulation for Warehouse {
+    fn step(&mut self) -> Result<String> {
+        let &command = self
+            .commands
+            .get(self.next)
+            .ok_or_else(|| anyhow!("All {} commands have run", self.commands.len()))?;
+        self.grid.move_robot_char(command)?;
+        self.next += 1;
 
-    let grid = Grid::new(sections.next().unwrap().to_string());
+        Ok(format!("Command {} of {}: {}, GPS sum {}", self.next, self.commands.len(), command, self.grid.gps()))
+    }
 
-    let commands = sections.next().unwrap().chars()
-        .filter(|c| *c != '\n')
-        .collect();
+    fn render(&self) -> String {
+        self.grid.render()
+    }
+}
+
+/// The warehouse map and the robot's commands. Each part builds its own
+/// `Grid` from the map, since part 2 works on a widened warehouse.
+pub type Input = (String, Vec<char>);
+
+pub fn parse_input(input: &str) -> Result<Input, Error> {
+    let (map, moves) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::missing(input, input, "Expected a blank line followed by the robot's moves"))?;
+
+    for line in map.lines() {
+        if let Some((i, c)) = line.char_indices().find(|(_, c)| !"#.O@".contains(*c)) {
+            return Err(Error::parse(input, &line[i..i + c.len_utf8()], "Expected one of #, ., O or @ on the map"));
+        }
+    }
+
+    let mut commands = Vec::new();
+    for line in moves.lines() {
+        for (i, c) in line.char_indices() {
+            if !"^>v<".contains(c) {
+                return Err(Error::parse(input, &line[i..i + c.len_utf8()], "Expected a move, one of ^, >, v or <"));
+            }
+            commands.push(c);
+        }
+    }
 
-    Ok((grid, commands))
+    Ok((map.to_string(), commands))
 }
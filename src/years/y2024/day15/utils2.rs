@@ -1,8 +1,7 @@
-use core::panic;
-use std::io::{self, Write};
-use std::fs;
 use strum_macros::EnumIter;
 use std::ops::Add;
+use crate::error::Error;
+use crate::trace;
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
 pub struct Coord {
@@ -36,7 +35,7 @@ pub enum Direction {
 }
 
 impl Direction {
-    pub fn to_offset(&self) -> (i32, i32) {
+    pub fn to_offset(self) -> (i32, i32) {
         match self {
             Direction::North => (-1, 0),
             Direction::East => (0, 1),
@@ -76,7 +75,7 @@ impl Element {
             Element::Wall => vec![Element::Wall, Element::Wall],
             Element::Obstacle => vec![Element::ObstacleLeft, Element::ObstacleRight],
             Element::Robot => vec![Element::Robot, Element::Empty],
-            Element::ObstacleLeft | Element::ObstacleRight => panic!("Invalid input"),
+            Element::ObstacleLeft | Element::ObstacleRight => unreachable!("parse_input only allows #, ., O and @ on the map"),
         }
     }
 }
@@ -149,28 +148,26 @@ impl Grid {
         }
     }
 
-    pub fn print_grid(&self) {
-        print!("\x1B[2J\x1B[H");
-        println!("Robot is at: {:?}", self.robot.coord);
-        for row in &self.grid {
-            println!("{}", row.iter().map(|&e| e.to_colored_char()).collect::<String>());
-        }
-        io::stdout().flush().unwrap();
-
-        // println!()
+    pub fn render(&self) -> String {
+        let rows: Vec<String> = self
+            .grid
+            .iter()
+            .map(|row| row.iter().map(|&e| e.to_colored_char()).collect())
+            .collect();
+        rows.join("\n")
     }
 
-    pub fn move_robot_char(&mut self, command: char) {
+    pub fn move_robot_char(&mut self, command: char) -> Result<(), Error> {
         match command {
             '^' => self.move_robot(Direction::North),
             '>' => self.move_robot(Direction::East),
             'v' => self.move_robot(Direction::South),
             '<' => self.move_robot(Direction::West),
-            _ => panic!("Invalid command"),
+            _ => Err(Error::InvalidState(format!("Unknown move {:?}", command))),
         }
     }
 
-    pub fn move_element(&mut self, current_coord: Coord, direction: Direction) {
+    pub fn move_element(&mut self, current_coord: Coord, direction: Direction) -> Result<(), Error> {
         let new_coord = current_coord + direction;
         let next_element = self.grid[new_coord.x as usize][new_coord.y as usize];
 
@@ -180,39 +177,38 @@ impl Grid {
                 self.grid[current_coord.x as usize][current_coord.y as usize] = Element::Empty;
             },
             Element::ObstacleLeft => {
-                self.move_element(new_coord + (0, 1), direction);
-                self.move_element(new_coord, direction);
+                self.move_element(new_coord + (0, 1), direction)?;
+                self.move_element(new_coord, direction)?;
                 self.grid[new_coord.x as usize][new_coord.y as usize] = self.grid[current_coord.x as usize][current_coord.y as usize];
                 self.grid[current_coord.x as usize][current_coord.y as usize] = Element::Empty;
 
             },
             Element::ObstacleRight => {
-                self.move_element(new_coord + (0, -1), direction);
-                self.move_element(new_coord, direction);
+                self.move_element(new_coord + (0, -1), direction)?;
+                self.move_element(new_coord, direction)?;
                 self.grid[new_coord.x as usize][new_coord.y as usize] = self.grid[current_coord.x as usize][current_coord.y as usize];
                 self.grid[current_coord.x as usize][current_coord.y as usize] = Element::Empty;
 
             }
-            _ => panic!("Invalid state")
+            other => return Err(Error::InvalidState(format!("Cannot push {:?} at {:?}", other, new_coord))),
         }
-
-
+        Ok(())
     }
 
-    pub fn can_move(&self,  current_coord: Coord, direction: Direction) -> bool {
+    pub fn can_move(&self,  current_coord: Coord, direction: Direction) -> Result<bool, Error> {
         let new_coord = current_coord + direction;
         let next_element = self.grid[new_coord.x as usize][new_coord.y as usize];
 
         match next_element {
-            Element::Empty => true,
-            Element::Wall => false,
+            Element::Empty => Ok(true),
+            Element::Wall => Ok(false),
             Element::ObstacleLeft => {
                 if direction == Direction::West {
                     self.can_move(new_coord, direction)
                 } else if direction == Direction::East {
                     self.can_move(new_coord + (0,1), direction)
                 } else {
-                    self.can_move(new_coord, direction) && self.can_move(new_coord + (0, 1), direction)
+                    Ok(self.can_move(new_coord, direction)? && self.can_move(new_coord + (0, 1), direction)?)
                 }
             },
             Element::ObstacleRight => {
@@ -222,31 +218,27 @@ impl Grid {
                 } else if direction == Direction::West {
                     self.can_move(new_coord + (0, -1), direction)
                 } else {
-                    self.can_move(new_coord, direction) && self.can_move(new_coord + (0, -1), direction)
+                    Ok(self.can_move(new_coord, direction)? && self.can_move(new_coord + (0, -1), direction)?)
                 }
             }
-            _ => panic!("Invalid state")
+            other => Err(Error::InvalidState(format!("Cannot push {:?} at {:?}", other, new_coord))),
         }
     }
 
-    pub fn move_robot(&mut self, direction: Direction) {
-
-        if self.can_move(self.robot.coord, direction) {
-            self.move_element(self.robot.coord, direction);
+    pub fn move_robot(&mut self, direction: Direction) -> Result<(), Error> {
+        if self.can_move(self.robot.coord, direction)? {
+            self.move_element(self.robot.coord, direction)?;
             self.robot = self.robot + direction;
-
         }
+        Ok(())
     }
 
-    pub fn process_commands(&mut self, commands: Vec<char>, visualize: bool) {
-        for c in commands {
-            self.move_robot_char(c);
-            
-            if visualize {
-                self.print_grid();
-                std::thread::sleep(std::time::Duration::from_millis(200));
-            }
+    pub fn process_commands(&mut self, commands: &[char]) -> Result<(), Error> {
+        for &c in commands {
+            self.move_robot_char(c)?;
+            trace!("After command {}, robot is at {:?}:\n{}", c, self.robot.coord, self.render());
         }
+        Ok(())
     }
 
     pub fn gps(&self) -> i32 {
@@ -262,17 +254,3 @@ impl Grid {
         sum
     }
 }
-
-pub fn read_input() -> io::Result<(Grid, Vec<char>)> {
-    let input = fs::read_to_string("inputs/day15/input.txt")?;
-
-    let mut sections = input.split("\n\n");
-
-    let grid = Grid::new(sections.next().unwrap().to_string());
-
-    let commands = sections.next().unwrap().chars()
-        .filter(|c| *c != '\n')
-        .collect();
-
-    Ok((grid, commands))
-}
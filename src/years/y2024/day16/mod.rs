@@ -0,0 +1,41 @@
+pub mod utils;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::shape;
+use crate::solution::{Answer, Params, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = utils::Grid;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 16;
+
+    fn validate(&self, input: &str) -> Result<()> {
+        shape::rectangular(input, input)?;
+        shape::only(input, input, "#.SE")?;
+        shape::exactly_one(input, input, 'S', "start S")?;
+        Ok(shape::exactly_one(input, input, 'E', "end E")?)
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input))
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+
+    fn render(&self, input: &Self::Input) -> Option<String> {
+        Some(input.render())
+    }
+}
@@ -0,0 +1,10 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::utils::Grid;
+
+pub fn solve(grid: &Grid) -> Result<Answer> {
+    let (cost, _) = grid.navigate();
+
+    Ok(cost.into())
+}
@@ -0,0 +1,13 @@
+use anyhow::Result;
+
+use crate::debug;
+use crate::solution::Answer;
+use super::utils::Grid;
+
+pub fn solve(grid: &Grid) -> Result<Answer> {
+    let (_, best_points) = grid.navigate();
+
+    debug!("Best seats:\n{}", grid.render_best_points(&best_points));
+
+    Ok(best_points.len().into())
+}
@@ -1,11 +1,11 @@
-use std::io::{self, Write};
-use std::fs;
 use strum_macros::EnumIter;
 use strum::IntoEnumIterator;
 use std::ops::Add;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap, HashSet};
 
+use crate::profile;
+
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
 pub struct Coord {
     pub x: i32,
@@ -38,7 +38,7 @@ pub enum Direction {
 }
 
 impl Direction {
-    pub fn to_offset(&self) -> (i32, i32) {
+    pub fn to_offset(self) -> (i32, i32) {
         match self {
             Direction::North => (-1, 0),
             Direction::East => (0, 1),
@@ -70,7 +70,7 @@ impl Robot {
     pub fn move_in_direction(&self) -> Self {
         let mut path = self.path.clone();
         path.push(self.coord);
-        Robot { coord: self.coord + self.direction, direction: self.direction, cost: self.cost + 1, path: path}
+        Robot { coord: self.coord + self.direction, direction: self.direction, cost: self.cost + 1, path}
     }
 
     pub fn turn(&self, direction: Direction) -> Self {
@@ -107,17 +107,6 @@ enum Element {
     End
 }
 
-impl Element {
-    fn to_colored_char(self) -> String {
-        match self {
-            Element::Empty => format!("\x1B[90m{}\x1B[0m", '.'),
-            Element::Wall => format!("\x1B[37;1m{}\x1B[0m", '#'),
-            Element::End => format!("\x1B[33m{}\x1B[0m", 'E'),
-            Element::Start => format!("\x1B[32;1m{}\x1B[0m", 'S'),
-        }
-    }
-}
-
 impl From<char> for Element {
     fn from(value: char) -> Self {
         match value {
@@ -172,32 +161,39 @@ impl Grid {
         }
     }
 
-    pub fn print_grid(&self) {
-        print!("\x1B[2J\x1B[H");
-        
-        for row in &self.grid {
-            println!("{}", row.iter().map(|&e| e.to_colored_char()).collect::<String>());
-        }
-        io::stdout().flush().unwrap();
-
-        println!()
-    }
-    
-    pub fn print_grid_best_points(&self, best_points: &HashSet<Coord>) {
-        for (i, row) in self.grid.iter().enumerate() {
-            for (j, element) in row.iter().enumerate() {
-                print!("{}", if best_points.contains(&Coord { x: i as i32, y: j as i32 }) {
-                    String::from("O")
-                } else {
-                    format!("{}", char::from(*element))
-                });
-            }
-            println!()
-        }
-        println!()
+    pub fn render(&self) -> String {
+        let rows: Vec<String> = self
+            .grid
+            .iter()
+            .map(|row| row.iter().map(|&element| char::from(element)).collect())
+            .collect();
+        rows.join("\n")
+    }
+
+    /// Like `render`, with every tile on a best path drawn as `O`.
+    pub fn render_best_points(&self, best_points: &HashSet<Coord>) -> String {
+        let rows: Vec<String> = self
+            .grid
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(j, &element)| {
+                        if best_points.contains(&Coord { x: i as i32, y: j as i32 }) {
+                            'O'
+                        } else {
+                            char::from(element)
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        rows.join("\n")
     }
 
     pub fn navigate(&self) -> (usize, HashSet<Coord>) {
+        let _span = profile::span("navigate");
         let mut heap = BinaryHeap::new();
         let mut visited = HashMap::new();
         let mut best_cost = usize::MAX;
@@ -249,10 +245,6 @@ impl Grid {
     }
 }
 
-pub fn read_input() -> io::Result<Grid> {
-    let input = fs::read_to_string("inputs/day16/input.txt")?;
-
-    let grid = Grid::new(input);
-
-    Ok(grid)
+pub fn parse_input(input: &str) -> Grid {
+    Grid::new(input.to_string())
 }
@@ -0,0 +1,66 @@
+pub mod utils;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::solution::{Answer, Params, Solution};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = (utils::Computer, Vec<usize>);
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 17;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+
+    fn solve(program: &str, part: Part) -> String {
+        let input = Day17.parse(&format!("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: {}", program)).unwrap();
+        let answer = match part {
+            Part::One => Day17.part1(&input, &Params::default()),
+            Part::Two => Day17.part2(&input, &Params::default()),
+        };
+        match answer {
+            Ok(answer) => answer.to_string(),
+            Err(err) => err.to_string(),
+        }
+    }
+
+    #[test]
+    fn solves_the_examples() {
+        assert_eq!(solve("0,1,5,4,3,0", Part::One), "4,6,3,5,6,3,5,2,1,0");
+        assert_eq!(solve("0,3,5,4,3,0", Part::Two), "117440");
+    }
+
+    #[test]
+    fn reports_a_program_that_cannot_print_itself() {
+        assert_eq!(
+            solve("0,1,5,4,3,0", Part::Two),
+            "No solution: No value of register A makes the program print itself"
+        );
+    }
+
+    #[test]
+    fn reports_invalid_operands() {
+        assert_eq!(solve("5,7", Part::One), "Invalid puzzle state: Invalid combo operand: 7");
+    }
+}
@@ -0,0 +1,18 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::utils::Computer;
+
+pub fn solve(input: &(Computer, Vec<usize>)) -> Result<Answer> {
+    let (computer, program) = input;
+    let mut computer = computer.clone();
+
+    let output = computer.run(program)?;
+
+    let output = output.iter()
+    .map(|num| num.to_string())
+    .collect::<Vec<_>>()
+    .join(","); 
+
+    Ok(output.into())
+}
@@ -0,0 +1,37 @@
+use anyhow::Result;
+
+use crate::error::Error;
+use crate::solution::Answer;
+use super::utils::Computer;
+
+pub fn solve(input: &(Computer, Vec<usize>)) -> Result<Answer> {
+    let (computer, program) = input;
+    let mut computer = computer.clone();
+
+    let mut value: usize = 0;
+    let mut times = 1;
+    loop {
+        computer.set_register('A', value);
+        computer.set_register('B', 0);
+        computer.set_register('C', 0);
+
+        let output = computer.run(program)?;
+
+        if output == *program {
+            break;
+        }
+        if output.len() > program.len() {
+            return Err(Error::NoSolution(String::from("No value of register A makes the program print itself")).into());
+        }
+        // Shorter output than `times` cannot end like the program yet.
+        if output.len() >= times && output[(output.len() - times)..] == program[(program.len() - times)..] {
+            times += 1;
+            value *= 8;
+            
+        } else {
+            value += 1
+        }
+    }
+
+    Ok(value.into())
+}
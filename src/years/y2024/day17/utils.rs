@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use regex::Regex;
+
+use once_cell::sync::Lazy;
+use crate::error::{parse_at, Error};
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    pub registers: HashMap<char, usize>
+}
+
+/// What an instruction outputs, if anything.
+pub type Step = Result<Option<usize>, Error>;
+
+pub trait Instruction {
+    fn execute(&self, operand: usize, registers: &mut HashMap<char, usize>, pointer: &mut usize) -> Step;
+
+    fn get_operand(&self, operand: usize, registers: &HashMap<char, usize>) -> Result<usize, Error> {
+        match operand {
+            0..=3 => Ok(operand),
+            4 => register(registers, 'A'),
+            5 => register(registers, 'B'),
+            6 => register(registers, 'C'),
+            _ => Err(Error::InvalidState(format!("Invalid combo operand: {}", operand))),
+        }
+    }
+}
+
+fn register(registers: &HashMap<char, usize>, name: char) -> Result<usize, Error> {
+    registers
+        .get(&name)
+        .copied()
+        .ok_or_else(|| Error::InvalidState(format!("Missing register {}", name)))
+}
+
+pub struct Adv;
+
+impl Instruction for Adv {
+    fn execute(&self, operand: usize, registers: &mut HashMap<char, usize>, pointer: &mut usize) -> Step {
+        let operand = self.get_operand(operand, registers)?;
+        let denominator = 2usize.pow(operand as u32);
+        registers.entry('A').and_modify(|e| *e /= denominator);
+        *pointer += 2;
+        Ok(None)
+    }
+}
+
+pub struct Bxl;
+
+impl Instruction for Bxl {
+    fn execute(&self, operand: usize, registers: &mut HashMap<char, usize>, pointer: &mut usize) -> Step {
+        registers.entry('B').and_modify(|e| *e ^= operand);
+        *pointer += 2;
+        Ok(None)
+    }
+}
+
+pub struct Bst;
+
+impl Instruction for Bst {
+    fn execute(&self, operand: usize, registers: &mut HashMap<char, usize>, pointer: &mut usize) -> Step {
+        let operand = self.get_operand(operand, registers)?;
+        let value = operand % 8;
+        registers.insert('B', value);
+        *pointer += 2;
+        Ok(None)
+    }
+}
+
+pub struct Jnz;
+
+impl Instruction for Jnz {
+    fn execute(&self, operand: usize, registers: &mut HashMap<char, usize>, pointer: &mut usize) -> Step {
+        if register(registers, 'A')? != 0 {
+            *pointer = operand;
+        } else {
+            *pointer += 2;
+        }
+        Ok(None)
+    }
+}
+
+pub struct Bxc;
+
+impl Instruction for Bxc {
+    fn execute(&self, _operand: usize, registers: &mut HashMap<char, usize>, pointer: &mut usize) -> Step {
+        let b_value = register(registers, 'B')?;
+        let c_value = register(registers, 'C')?;
+        registers.insert('B', b_value ^ c_value);
+        *pointer += 2;
+        Ok(None)
+    }
+}
+
+pub struct Out;
+
+impl Instruction for Out {
+    fn execute(&self, operand: usize, registers: &mut HashMap<char, usize>, pointer: &mut usize) -> Step {
+        let operand = self.get_operand(operand, registers)?;
+        let value = operand % 8;
+        *pointer += 2;
+
+        Ok(Some(value))
+    }
+}
+
+pub struct Bdv;
+
+impl Instruction for Bdv {
+    fn execute(&self, operand: usize, registers: &mut HashMap<char, usize>, pointer: &mut usize) -> Step {
+        let operand = self.get_operand(operand, registers)?;
+        let denominator = 2usize.pow(operand as u32);
+        let result = register(registers, 'A')? / denominator;
+        registers.insert('B', result);
+        *pointer += 2;
+        Ok(None)
+    }
+}
+
+pub struct Cdv;
+
+impl Instruction for Cdv {
+    fn execute(&self, operand: usize, registers: &mut HashMap<char, usize>, pointer: &mut usize) -> Step {
+        let operand = self.get_operand(operand, registers)?;
+        let denominator = 2usize.pow(operand as u32);
+        let result = register(registers, 'A')? / denominator;
+        registers.insert('C', result);
+        *pointer += 2;
+        Ok(None)
+    }
+}
+
+static INSTRUCTIONS: Lazy<HashMap<usize, Box<dyn Instruction  + Send + Sync>>> = Lazy::new(|| {
+    let mut map: HashMap<usize, Box<dyn Instruction + Send + Sync>> = HashMap::new();
+    map.insert(0, Box::new(Adv));
+    map.insert(1, Box::new(Bxl));
+    map.insert(2, Box::new(Bst));
+    map.insert(3, Box::new(Jnz));
+    map.insert(4, Box::new(Bxc));
+    map.insert(5, Box::new(Out));
+    map.insert(6, Box::new(Bdv));
+    map.insert(7, Box::new(Cdv));
+    map
+});
+
+
+impl Computer {
+    pub fn new(registers: HashMap<char, usize>) -> Computer {
+        Computer {
+            registers
+        }
+    }
+
+    pub fn set_register(&mut self, register: char, value: usize) {
+        self.registers.insert(register, value);
+    }
+
+    pub fn run(&mut self, program: &[usize]) -> Result<Vec<usize>, Error> {
+        let mut pointer: usize = 0;
+        let mut outputs: Vec<usize> = Vec::new();
+    
+        while pointer < program.len() {
+            let opcode = program[pointer];
+            let operand = *program
+                .get(pointer + 1)
+                .ok_or_else(|| Error::InvalidState(format!("Jumped to {}, which has no operand", pointer)))?;
+    
+            if let Some(instruction) = INSTRUCTIONS.get(&opcode) {
+                if let Some(output) = instruction.execute(operand, &mut self.registers, &mut pointer)? {
+                    outputs.push(output);
+                }
+            } else {
+                return Err(Error::InvalidState(format!("Invalid opcode: {} at pointer {}", opcode, pointer)));
+            }
+        }
+    
+        Ok(outputs)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(Computer, Vec<usize>), Error> {
+    let register_regex = Regex::new(r"Register ([A-Z]): (\d+)").unwrap();
+    let (register_section, program_section) = input
+        .split_once("\n\n")
+        .ok_or_else(|| Error::missing(input, input, "Expected a blank line followed by the program"))?;
+
+    let mut registers = HashMap::new();
+    for line in register_section.lines() {
+        let captures = register_regex
+            .captures(line)
+            .ok_or_else(|| Error::parse(input, line, "Expected a register such as Register A: 729"))?;
+        let register_name = captures[1].chars().next().unwrap();
+        registers.insert(register_name, parse_at(input, &captures[2], "a register value")?);
+    }
+    for name in ['A', 'B', 'C'] {
+        if !registers.contains_key(&name) {
+            return Err(Error::missing(input, register_section, format!("Missing register {}", name)));
+        }
+    }
+
+    let program_line = program_section.split('\n').next().unwrap_or(program_section);
+    let program_part = program_section
+        .strip_prefix("Program: ")
+        .ok_or_else(|| Error::parse(input, program_line, "Expected the program such as Program: 0,1,5,4,3,0"))?;
+
+    let program: Vec<usize> = program_part
+        .split(',')
+        .map(|s| match parse_at(input, s, "a 3-bit number")? {
+            value @ 0..=7 => Ok(value),
+            _ => Err(Error::parse(input, s, "Expected a 3-bit number")),
+        })
+        .collect::<Result<_, _>>()?;
+    if !program.len().is_multiple_of(2) {
+        return Err(Error::missing(input, program_part, "Expected an operand after the last instruction"));
+    }
+
+    Ok((Computer::new(registers), program))
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+pub mod utils;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::solution::{Answer, Param, Params, Part, Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<utils::Coord>;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 18;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input, params: &Params) -> Result<Answer> {
+        part1::solve(input, params)
+    }
+
+    fn part2(&self, input: &Self::Input, params: &Params) -> Result<Answer> {
+        part2::solve(input, params)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param { name: "size", part: None, default: 71, description: "Width and height of the memory space" },
+            Param { name: "bytes", part: Some(Part::One), default: 1024, description: "Bytes that have fallen before walking" },
+        ]
+    }
+}
@@ -0,0 +1,15 @@
+use anyhow::Result;
+
+use crate::error::Error;
+use crate::solution::{Answer, Params};
+use super::utils::{Coord, Grid};
+
+pub fn solve(coords: &[Coord], params: &Params) -> Result<Answer> {
+    let size = params.get("size")?;
+    let mut grid = Grid::new(size, size);
+    grid.apply_obstacles(coords, params.get("bytes")?);
+
+    let steps =  grid.navigate().ok_or_else(|| Error::NoSolution(String::from("No path to the exit")))?;
+
+    Ok(steps.into())
+}
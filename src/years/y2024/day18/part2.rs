@@ -0,0 +1,25 @@
+use anyhow::Result;
+
+use crate::error::Error;
+use crate::solution::{Answer, Params};
+use super::utils::{Coord, Grid};
+
+pub fn solve(coords: &[Coord], params: &Params) -> Result<Answer> {
+    let size = params.get("size")?;
+    let mut first_blocking = 0;
+    for i in 0..coords.len() {
+        let mut grid = Grid::new(size, size);
+        grid.apply_obstacles(coords, coords.len() - i);
+        if grid.navigate().is_some() {
+            first_blocking = coords.len() - i;
+            break;
+        }
+    }
+
+    let coord = coords
+        .get(first_blocking)
+        .ok_or_else(|| Error::NoSolution(String::from("The exit stays reachable after every byte has fallen")))?;
+
+    //coords are stored row and column based, the answer is x,y
+    Ok(Answer::Coord(coord.y as i64, coord.x as i64))
+}
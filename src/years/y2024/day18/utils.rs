@@ -1,7 +1,5 @@
-use std::fs;
 use std::fmt;
 use std::hash::Hash;
-use std::io;
 use std::collections::HashMap;
 use strum_macros::EnumIter;
 use strum::IntoEnumIterator;
@@ -9,6 +7,8 @@ use std::collections::{BinaryHeap};
 use std::ops::Add;
 use std::cmp::Ordering;
 
+use crate::error::{parse_at, Error};
+
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
 pub struct Coord {
@@ -48,7 +48,7 @@ pub enum Direction {
 }
 
 impl Direction {
-    pub fn to_offset(&self) -> (i32, i32) {
+    pub fn to_offset(self) -> (i32, i32) {
         match self {
             Direction::North => (-1, 0),
             Direction::East => (0, 1),
@@ -126,7 +126,7 @@ impl Grid {
             && coord.y < self.grid[0].len() as i32
     }
 
-    pub fn apply_obstacles(&mut self, obstacles: &Vec<Coord>, ticks: usize) {
+    pub fn apply_obstacles(&mut self, obstacles: &[Coord], ticks: usize) {
         for obstacle in obstacles.iter().take(ticks) {
             self.grid[obstacle.x as usize][obstacle.y as usize] = Element::Obstacle;
         }
@@ -156,27 +156,24 @@ impl Grid {
             for direction in Direction::iter() {
                 let new_robot = current.move_in_direction(direction);
                 
-                if self.is_within_bounds(new_robot.coord) {
-                    if self.grid[new_robot.coord.x as usize][new_robot.coord.y as usize] != Element::Obstacle {
+                if self.is_within_bounds(new_robot.coord)
+                    && self.grid[new_robot.coord.x as usize][new_robot.coord.y as usize] != Element::Obstacle {
                         heap.push(new_robot);
                     }
-                }
             } 
         }
         None
     }
 }
 
-pub fn read_input() -> io::Result<Vec<Coord>> {
-    let input = fs::read_to_string("inputs/day18/input.txt")?;
-
-    let coords = input.lines().map(|line| {
-        let mut split = line.split(",");
-        let x = split.next().unwrap().parse::<usize>().unwrap();
-        let y = split.next().unwrap().parse::<usize>().unwrap();
+pub fn parse_input(input: &str) -> Result<Vec<Coord>, Error> {
+    input.lines().map(|line| {
+        let Some((x, y)) = line.split_once(",") else {
+            return Err(Error::missing(input, line, "Expected a coordinate such as 6,1"));
+        };
+        let x = parse_at::<usize>(input, x, "a number")?;
+        let y = parse_at::<usize>(input, y, "a number")?;
         //easier to have it row and column based
-        Coord { x: y as i32, y: x as i32 }
-    }).collect();
-
-    Ok(coords)
+        Ok(Coord { x: y as i32, y: x as i32 })
+    }).collect()
 }
\ No newline at end of file
@@ -0,0 +1,35 @@
+pub mod utils;
+pub mod utils2;
+pub mod part1;
+pub mod part2;
+pub mod part1_counting;
+
+use anyhow::Result;
+
+use crate::solution::{Answer, Params, Part, Solution, Variant};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = utils::Input;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 19;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input))
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input>> {
+        vec![Variant { part: Part::One, name: "counting", solve: |input, _| part1_counting::solve(input) }]
+    }
+}
@@ -0,0 +1,21 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::utils::Input;
+use super::utils2::count_patterns;
+
+/// Part 1 on top of part 2's arrangement counting: a design is possible
+/// when it has at least one arrangement.
+pub fn solve(input: &Input) -> Result<Answer> {
+    let (available_towels, patterns) = input;
+
+    let mut cache = HashMap::new();
+    let count = patterns
+        .iter()
+        .filter(|pattern| count_patterns(pattern, available_towels, &mut cache) > 0)
+        .count();
+
+    Ok(count.into())
+}
@@ -0,0 +1,13 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::utils::Input;
+use super::utils2::check_patterns;
+
+pub fn solve(input: &Input) -> Result<Answer> {
+    let (available_towels, patterns) = input;
+
+    let count = check_patterns(available_towels, patterns);
+
+    Ok(count.into())
+}
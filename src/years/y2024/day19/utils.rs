@@ -1,5 +1,3 @@
-use std::io;
-use std::fs;
 use std::collections::{HashMap, HashSet};
 
 pub fn can_form_pattern(pattern: &str, available_towels: &HashSet<String>, cache: &mut HashMap<String, bool>) -> bool {
@@ -27,10 +25,10 @@ pub fn can_form_pattern(pattern: &str, available_towels: &HashSet<String>, cache
 }
 
 
-pub fn check_patterns(available_towels: HashSet<String>, patterns: Vec<String>) -> usize {
+pub fn check_patterns(available_towels: &HashSet<String>, patterns: &[String]) -> usize {
     let mut count = 0;
     for pattern in patterns {
-        if can_form_pattern(&pattern, &available_towels, &mut HashMap::new()) {
+        if can_form_pattern(pattern, available_towels, &mut HashMap::new()) {
             count += 1;
         }
     }
@@ -39,9 +37,9 @@ pub fn check_patterns(available_towels: HashSet<String>, patterns: Vec<String>)
 }
 
 
-pub fn read_input() -> io::Result<(HashSet<String>, Vec<String>)> {
-    let input = fs::read_to_string("inputs/day19/input.txt")?;
+pub type Input = (HashSet<String>, Vec<String>);
 
+pub fn parse_input(input: &str) -> Input {
     let mut sections = input.split("\n\n");
 
     let available_towels = sections
@@ -58,5 +56,5 @@ pub fn read_input() -> io::Result<(HashSet<String>, Vec<String>)> {
         .map(|line| line.to_string()) 
         .collect();
 
-    Ok((available_towels, patterns))
+    (available_towels, patterns)
 }
\ No newline at end of file
@@ -0,0 +1,36 @@
+use std::collections::{HashMap, HashSet};
+
+pub fn count_patterns(pattern: &str, available_towels: &HashSet<String>, cache: &mut HashMap<String, usize>) -> usize {
+    
+    if cache.contains_key(pattern) {
+        return cache[pattern];
+    }
+    
+    if pattern.is_empty() {
+        return 1;
+    }
+
+    let mut ways = 0;
+
+    for i in 1..=pattern.len() {
+        let prefix = &pattern[0..i];
+        if available_towels.contains(prefix) {
+            let suffix = &pattern[i..];
+            ways += count_patterns(suffix, available_towels, cache);
+        }
+    }
+    cache.insert(pattern.to_string(), ways);
+    ways
+}
+
+
+pub fn check_patterns(available_towels: &HashSet<String>, patterns: &[String]) -> usize {
+    let mut count = 0;
+    for pattern in patterns {
+        let ways = count_patterns(pattern, available_towels, &mut HashMap::new());
+        count += ways;
+
+    }
+    
+    count
+}
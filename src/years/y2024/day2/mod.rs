@@ -0,0 +1,38 @@
+pub mod utils;
+pub mod part1;
+pub mod part2;
+pub mod part1_optimized;
+pub mod part2_optimized;
+
+use anyhow::Result;
+
+use crate::solution::{Answer, Params, Part, Solution, Variant};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 2;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+
+    fn variants(&self) -> Vec<Variant<Self::Input>> {
+        vec![
+            Variant { part: Part::One, name: "optimized", solve: |input, _| part1_optimized::solve(input) },
+            Variant { part: Part::Two, name: "optimized", solve: |input, _| part2_optimized::solve(input) },
+        ]
+    }
+}
@@ -0,0 +1,26 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+
+fn is_safe(report: &[i32]) -> bool {
+    let increasing = report
+        .windows(2)
+        .all(|pair| pair[1] > pair[0] && (pair[1] - pair[0]) <= 3);
+    let decreasing = report
+        .windows(2)
+        .all(|pair| pair[0] > pair[1] && (pair[0] - pair[1]) <= 3);
+    
+    increasing || decreasing
+}
+
+pub fn solve(reports: &[Vec<i32>]) -> Result<Answer> {
+    let mut num_safe_reports = 0;
+
+    for report in reports {
+        if is_safe(report) {
+            num_safe_reports += 1;
+        }
+    }
+
+    Ok(num_safe_reports.into())
+}
@@ -1,6 +1,8 @@
-use std::fs;
+use anyhow::Result;
 
-fn is_safe(report: Vec<i32>) -> bool {
+use crate::solution::Answer;
+
+fn is_safe(report: &[i32]) -> bool {
     let mut increasing = true;
     let mut decreasing = true;
 
@@ -9,7 +11,7 @@ fn is_safe(report: Vec<i32>) -> bool {
         if diff <= 0 || diff > 3 {
             increasing = false;
         }
-        if diff >= 0 || diff < -3 {
+        if !(-3..0).contains(&diff) {
             decreasing = false;
         }
         if !increasing && !decreasing {
@@ -20,22 +22,14 @@ fn is_safe(report: Vec<i32>) -> bool {
     increasing || decreasing
 }
 
-pub fn solve() -> Result<(), Box<dyn std::error::Error>> {
-    let input = fs::read_to_string("inputs/day2/input.txt")?;
-
+pub fn solve(reports: &[Vec<i32>]) -> Result<Answer> {
     let mut num_safe_reports = 0;
 
-    for line in input.lines() {
-        let report = line.split_whitespace()
-        .map(|s| s.parse::<i32>().unwrap())
-        .collect();
-        
+    for report in reports {
         if is_safe(report) {
             num_safe_reports += 1;
         }
     }
 
-    println!("Day 2 Part 1: {}", num_safe_reports);
-
-    Ok(())
-}
\ No newline at end of file
+    Ok(num_safe_reports.into())
+}
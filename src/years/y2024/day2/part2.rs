@@ -1,6 +1,8 @@
-use std::fs;
+use anyhow::Result;
 
-fn is_safe(report: &Vec<i32>) -> bool {
+use crate::solution::Answer;
+
+fn is_safe(report: &[i32]) -> bool {
     let increasing = report
         .windows(2)
         .all(|pair| pair[1] > pair[0] && (pair[1] - pair[0]) <= 3);
@@ -11,32 +13,26 @@ fn is_safe(report: &Vec<i32>) -> bool {
     increasing || decreasing
 }
 
-fn one_element_dropped(vec: Vec<i32>) -> Vec<Vec<i32>> {
+fn one_element_dropped(vec: &[i32]) -> Vec<Vec<i32>> {
     (0..vec.len())
         .map(|i| {
-            let mut new_vec = vec.clone();
+            let mut new_vec = vec.to_vec();
             new_vec.remove(i);
             new_vec
         })
         .collect()
 }
 
-pub fn solve() -> Result<(), Box<dyn std::error::Error>> {
-    let input = fs::read_to_string("inputs/day2/input.txt")?;
-
+pub fn solve(reports: &[Vec<i32>]) -> Result<Answer> {
     let mut num_safe_reports = 0;
 
-    for line in input.lines() {
-        let report = line.split_whitespace()
-        .map(|s| s.parse::<i32>().unwrap())
-        .collect();
-
+    for report in reports {
         let one_element_dropped_reports = one_element_dropped(report);
 
         let any_safe = one_element_dropped_reports
             .iter()
             .any(|report| {
-                is_safe(&report)
+                is_safe(report)
             });
         
         if any_safe {
@@ -44,7 +40,5 @@ pub fn solve() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    println!("Day 2 Part 2: {}", num_safe_reports);
-
-    Ok(())
-}
\ No newline at end of file
+    Ok(num_safe_reports.into())
+}
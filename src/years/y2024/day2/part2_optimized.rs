@@ -1,4 +1,6 @@
-use std::fs;
+use anyhow::Result;
+
+use crate::solution::Answer;
 
 #[derive(PartialEq)]
 enum ReportState {
@@ -7,7 +9,7 @@ enum ReportState {
     Ascending,
 }
 
-fn is_safe(report: Vec<i32>) -> bool {
+fn is_safe(report: &[i32]) -> bool {
     if report.len() < 2 {
         return true;
     }
@@ -39,22 +41,14 @@ fn is_safe(report: Vec<i32>) -> bool {
     true
 }
 
-pub fn solve() -> Result<(), Box<dyn std::error::Error>> {
-    let input = fs::read_to_string("inputs/day2/input.txt")?;
-
+pub fn solve(reports: &[Vec<i32>]) -> Result<Answer> {
     let mut num_safe_reports = 0;
 
-    for line in input.lines() {
-        let report = line.split_whitespace()
-        .map(|s| s.parse::<i32>().unwrap())
-        .collect();
-        
+    for report in reports {
         if is_safe(report) {
             num_safe_reports += 1;
         }
     }
 
-    println!("Day 2 Part 2: {}", num_safe_reports);
-
-    Ok(())
-}
\ No newline at end of file
+    Ok(num_safe_reports.into())
+}
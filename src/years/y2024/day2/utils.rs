@@ -0,0 +1,9 @@
+use crate::error::{parse_at, Error};
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, Error> {
+    input.lines()
+        .map(|line| line.split_whitespace()
+            .map(|s| parse_at(input, s, "a number"))
+            .collect())
+        .collect()
+}
@@ -0,0 +1,49 @@
+pub mod utils;
+/// Both parts, which differ only in their parameters.
+pub mod solve;
+
+use anyhow::Result;
+
+use crate::shape;
+use crate::solution::{Answer, Param, Params, Part, Solution};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = utils::Grid;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 20;
+
+    fn validate(&self, input: &str) -> Result<()> {
+        shape::rectangular(input, input)?;
+        shape::only(input, input, "#.SE")?;
+        shape::exactly_one(input, input, 'S', "start S")?;
+        Ok(shape::exactly_one(input, input, 'E', "end E")?)
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input))
+    }
+
+    fn part1(&self, input: &Self::Input, params: &Params) -> Result<Answer> {
+        solve::solve(input, params)
+    }
+
+    fn part2(&self, input: &Self::Input, params: &Params) -> Result<Answer> {
+        solve::solve(input, params)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param { name: "min_saving", part: None, default: 100, description: "Picoseconds a cheat must save to be counted" },
+            Param { name: "cheat_length", part: Some(Part::One), default: 2, description: "Picoseconds a cheat may last" },
+            Param { name: "cheat_length", part: Some(Part::Two), default: 20, description: "Picoseconds a cheat may last" },
+        ]
+    }
+
+    fn render(&self, input: &Self::Input) -> Option<String> {
+        Some(input.render())
+    }
+}
@@ -0,0 +1,13 @@
+use anyhow::Result;
+
+use crate::solution::{Answer, Params};
+use super::utils::Grid;
+
+pub fn solve(grid: &Grid, params: &Params) -> Result<Answer> {
+    let min_saving = params.get("min_saving")?;
+    let cheats = grid.find_cheats(min_saving, params.get("cheat_length")?);
+
+    let num_min_duration = cheats.values().sum::<usize>();
+    
+    Ok(num_min_duration.into())
+}
@@ -1,12 +1,12 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs;
 use std::fmt;
 use std::hash::Hash;
-use std::io;
 use strum_macros::EnumIter;
 use strum::IntoEnumIterator;
 use std::ops::Add;
+use crate::debug;
+use crate::profile;
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
 pub struct Coord {
@@ -47,7 +47,7 @@ pub enum Direction {
 }
 
 impl Direction {
-    pub fn to_offset(&self) -> (i32, i32) {
+    pub fn to_offset(self) -> (i32, i32) {
         match self {
             Direction::North => (-1, 0),
             Direction::East => (0, 1),
@@ -55,15 +55,6 @@ impl Direction {
             Direction::West => (0, -1),
         }
     }
-
-    pub fn opposite(self) -> Self {
-        match self {
-            Direction::North => Direction::South,
-            Direction::South => Direction::North,
-            Direction::East  => Direction::West,
-            Direction::West  => Direction::East,
-        }
-    }
 }
 
 #[derive(PartialEq, Clone, Copy, Debug)]
@@ -123,11 +114,20 @@ impl Grid {
             grid.push(row);
         }
 
-        println!("Start: {}, End: {}", start, end);
+        debug!("Start: {}, End: {}", start, end);
 
         Grid { grid, start, end }
     }
 
+    pub fn render(&self) -> String {
+        let rows: Vec<String> = self
+            .grid
+            .iter()
+            .map(|row| row.iter().map(|&element| char::from(element)).collect())
+            .collect();
+        rows.join("\n")
+    }
+
     fn is_within_bounds(&self, coord: Coord) -> bool {
         coord.x >= 0
             && coord.x < self.grid.len() as i32
@@ -185,6 +185,7 @@ impl Grid {
     }
 
     pub fn find_cheats(&self, min_duration: usize, allowed_distance: usize) -> HashMap<usize, usize> {
+        let _span = profile::span("find_cheats");
         let (path, distances) = self.race_path();
         let mut cheats  = HashMap::new();
 
@@ -207,8 +208,6 @@ impl Grid {
     }
 }
 
-pub fn read_input() -> io::Result<Grid> {
-    let input = fs::read_to_string("inputs/day20/input.txt")?;
-
-    Ok(Grid::new(input))
+pub fn parse_input(input: &str) -> Grid {
+    Grid::new(input.to_string())
 }
\ No newline at end of file
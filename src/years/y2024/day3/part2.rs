@@ -1,11 +1,11 @@
+use anyhow::Result;
 use regex::Regex;
-use std::fs;
 
-pub fn solve() -> Result<(), Box<dyn std::error::Error>> {
+use crate::solution::Answer;
 
-    let re = Regex::new(r"(?P<mul>mul\((\d+),(\d+)\))|(?P<do>do\(\))|(?P<dont>don't\(\))").unwrap();
+pub fn solve(input: &str) -> Result<Answer> {
 
-    let input = fs::read_to_string("inputs/day3/input.txt")?;
+    let re = Regex::new(r"(?P<mul>mul\((\d+),(\d+)\))|(?P<do>do\(\))|(?P<dont>don't\(\))").unwrap();
 
     let mut result = 0;
 
@@ -15,18 +15,18 @@ pub fn solve() -> Result<(), Box<dyn std::error::Error>> {
         let mut sum = 0;
         
         
-        for capture in re.captures_iter(&line) {
-            if let Some(_) = capture.name("mul") {
+        for capture in re.captures_iter(line) {
+            if capture.name("mul").is_some() {
                 if is_enabled {
                     let num1: i32 = capture[2].parse().unwrap();
                     let num2: i32 = capture[3].parse().unwrap();
                     sum += num1 * num2;
                 }
             }
-            else if let Some(_) = capture.name("do") {
+            else if capture.name("do").is_some() {
                 is_enabled = true;
             }
-            else if let Some(_) = capture.name("dont") {
+            else if capture.name("dont").is_some() {
                 is_enabled = false;
             }
         }
@@ -35,7 +35,5 @@ pub fn solve() -> Result<(), Box<dyn std::error::Error>> {
 
     }
 
-    println!("Day 3 Part 2: {}", result);
-
-    Ok(())
-}
\ No newline at end of file
+    Ok(result.into())
+}
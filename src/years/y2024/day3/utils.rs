@@ -0,0 +1,3 @@
+pub fn parse_input(input: &str) -> String {
+    input.to_string()
+}
@@ -0,0 +1,39 @@
+pub mod utils;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::shape;
+use crate::solution::{Answer, Params, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Vec<char>>;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 4;
+
+    fn validate(&self, input: &str) -> Result<()> {
+        Ok(shape::rectangular(input, input)?)
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_grid(input))
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+
+    fn render(&self, input: &Self::Input) -> Option<String> {
+        let rows: Vec<String> = input.iter().map(|row| row.iter().collect()).collect();
+        Some(rows.join("\n"))
+    }
+}
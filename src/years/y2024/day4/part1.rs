@@ -1,20 +1,9 @@
-use std::fs;
-use std::io;
+use anyhow::Result;
 
-fn read_grid() -> io::Result<Vec<Vec<char>>> {
-    let input = fs::read_to_string("inputs/day4/input.txt")?;
-    let mut grid = Vec::new();
-    
-    for line in input.lines() {
-        grid.push(line.chars().collect());
-    }
-
-    Ok(grid)
-}
+use crate::solution::Answer;
 
-pub fn solve() -> Result<(), Box<dyn std::error::Error>> {
+pub fn solve(grid: &[Vec<char>]) -> Result<Answer> {
     
-    let grid = read_grid().expect("Failed to read input");
     let word = "XMAS";
     let word_rev: String = word.chars().rev().collect();
     let word_len = word.len();
@@ -56,7 +45,5 @@ pub fn solve() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    println!("Day 4 Part 1: {}", count / 2);
-
-    Ok(())
-}
\ No newline at end of file
+    Ok((count / 2).into())
+}
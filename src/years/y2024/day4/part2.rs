@@ -0,0 +1,32 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+
+fn check(grid: &[Vec<char>], i: usize, j: usize) -> bool {
+    if grid[i][j] != 'A' {
+        return false
+    }
+
+    ((grid[i-1][j-1] == 'M' && grid[i+1][j+1] == 'S') || (grid[i-1][j-1] == 'S' && grid[i+1][j+1] == 'M'))
+        && ((grid[i+1][j-1] == 'M' && grid[i-1][j+1] == 'S') || (grid[i+1][j-1] == 'S' && grid[i-1][j+1] == 'M'))
+
+}
+
+pub fn solve(grid: &[Vec<char>]) -> Result<Answer> {
+    
+    let mut count = 0;
+
+    let rows = grid.len();
+    let cols = grid[0].len();
+
+    for i in 1..rows - 1 {
+        for j in 1..cols - 1 {
+            if check(grid, i, j) {
+                count += 1;
+            }
+            
+        }
+    }
+
+    Ok(count.into())
+}
@@ -0,0 +1,9 @@
+pub fn parse_grid(input: &str) -> Vec<Vec<char>> {
+    let mut grid = Vec::new();
+    
+    for line in input.lines() {
+        grid.push(line.chars().collect());
+    }
+
+    grid
+}
@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+
+use crate::error::{parse_at, Error};
+
+pub type Orderings = HashMap<i32, Vec<i32>>;
+
+pub type Input = (Orderings, Vec<Vec<i32>>, Orderings);
+
+pub fn parse_input(input: &str) -> Result<Input, Error> {
+    let mut before_orderings = HashMap::new();
+    let mut after_orderings = HashMap::new();
+    let mut updates = Vec::new();
+    let mut is_updates_section = false;
+
+    for line in input.lines() {
+        if line.is_empty() {
+            is_updates_section = true;
+            continue;
+        }
+        if is_updates_section {
+            let pages = line.split(",").map(|page| parse_at(input, page, "a page number"));
+            updates.push(pages.collect::<Result<_, _>>()?);
+
+        } else {
+            let Some((before, after)) = line.split_once("|") else {
+                return Err(Error::missing(input, line, "Expected an ordering rule such as 47|53"));
+            };
+            let before = parse_at(input, before, "a page number")?;
+            let after = parse_at(input, after, "a page number")?;
+            before_orderings.entry(before).or_insert(Vec::new()).push(after);
+            after_orderings.entry(after).or_insert(Vec::new()).push(before);
+        }
+    }
+
+    Ok((before_orderings, updates, after_orderings))
+}
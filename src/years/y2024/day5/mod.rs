@@ -0,0 +1,29 @@
+mod input;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::solution::{Answer, Params, Solution};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = input::Input;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 5;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input::parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
@@ -1,28 +1,30 @@
 use std::collections::HashMap;
 
-use crate::days::day5::input::read_input;
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::input::Input;
 
 pub fn check_valid(page1: i32, page2: i32, orderings: &HashMap<i32, Vec<i32>>) -> bool {
-    if orderings.contains_key(&page2) {
-        let page_orderings = orderings.get(&page2).unwrap();
+    if let Some(page_orderings) = orderings.get(&page2) {
         if page_orderings.contains(&page1) {
             return false;
         }
     }
-    return true;
+    true
     
 }
 
-pub fn solve() -> Result<(), Box<dyn std::error::Error>> {
+pub fn solve(input: &Input) -> Result<Answer> {
     
-    let (before_orderings, updates, _) = read_input().expect("Failed to read input");
+    let (before_orderings, updates, _) = input;
     let mut sum = 0;
 
     for update in updates {
         let mut is_valid = true;
         'outer: for i in 0..update.len() {
             for j in i + 1..update.len() {
-                if !check_valid(update[i], update[j], &before_orderings) {
+                if !check_valid(update[i], update[j], before_orderings) {
                     is_valid = false;
                     break 'outer;
                 }
@@ -36,7 +38,5 @@ pub fn solve() -> Result<(), Box<dyn std::error::Error>> {
     }
     
     
-    println!("Day 5 Part 1: {}", sum);
-
-    Ok(())
-}
\ No newline at end of file
+    Ok(sum.into())
+}
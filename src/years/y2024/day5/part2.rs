@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::input::Input;
+
+pub fn check_valid(page1: i32, page2: i32, orderings: &HashMap<i32, Vec<i32>>) -> bool {
+    if let Some(page_orderings) = orderings.get(&page2) {
+        if page_orderings.contains(&page1) {
+            return false;
+        }
+    }
+    true
+}
+
+pub fn find_middle(update: &[i32], before_orderings: &HashMap<i32, Vec<i32>>, after_orderings: &HashMap<i32, Vec<i32>>) -> Option<i32> {
+    for (i, &page) in update.iter().enumerate() {
+        let mut others = HashSet::new();
+        for (j, &other) in update.iter().enumerate() {
+            if i != j {
+                others.insert(other);
+            }
+        }
+        let before_order: HashSet<_> = before_orderings.get(&page).unwrap_or(&Vec::new()).iter().cloned().collect();
+        let after_order: HashSet<_> = after_orderings.get(&page).unwrap_or(&Vec::new()).iter().cloned().collect();
+
+        let befores: HashSet<_> = before_order.intersection(&others).collect();
+        let afters: HashSet<_> = after_order.intersection(&others).collect();
+
+        if befores.len() == afters.len() && befores.len() == update.len() / 2 {
+            return Some(page);
+        }
+    }
+
+    None
+}
+
+pub fn solve(input: &Input) -> Result<Answer> {
+    
+    let (before_orderings, updates, after_orderings) = input;
+    let mut sum = 0;
+
+    for update in updates {
+        let mut is_valid = true;
+        'outer: for i in 0..update.len() {
+            for j in i + 1..update.len() {
+                if !check_valid(update[i], update[j], before_orderings) {
+                    is_valid = false;
+                    break 'outer;
+                }
+            }
+        }
+        if !is_valid {
+            
+            if let Some(middle) = find_middle(update, before_orderings, after_orderings) {
+                sum += middle;
+            }
+            
+        }
+    }
+    
+    Ok(sum.into())
+}
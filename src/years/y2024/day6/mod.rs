@@ -0,0 +1,67 @@
+pub mod utils;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::shape;
+use crate::solution::{Answer, Params, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = utils::Input;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 6;
+
+    fn validate(&self, input: &str) -> Result<()> {
+        shape::rectangular(input, input)?;
+        shape::only(input, input, ".#^")?;
+        Ok(shape::exactly_one(input, input, '^', "guard ^")?)
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input))
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+
+    fn render(&self, input: &Self::Input) -> Option<String> {
+        let rows: Vec<String> = input.0.iter().map(|row| row.iter().collect()).collect();
+        Some(rows.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walks_a_grid_taller_than_it_is_wide() {
+        let input = Day6.parse(".#\n..\n#.\n^.").unwrap();
+        assert_eq!(Day6.part1(&input, &Params::default()).unwrap().to_string(), "2");
+        assert_eq!(Day6.part2(&input, &Params::default()).unwrap().to_string(), "0");
+    }
+
+    #[test]
+    fn walks_a_grid_wider_than_it_is_tall() {
+        let input = Day6.parse("...#\n^...").unwrap();
+        assert_eq!(Day6.part1(&input, &Params::default()).unwrap().to_string(), "2");
+    }
+
+    #[test]
+    fn reports_a_guard_that_never_leaves() {
+        let input = Day6.parse(".#..\n...#\n#^..\n..#.").unwrap();
+        let message = "No solution: The guard walks in a loop and never leaves the map";
+        assert_eq!(Day6.part1(&input, &Params::default()).unwrap_err().to_string(), message);
+        assert_eq!(Day6.part2(&input, &Params::default()).unwrap_err().to_string(), message);
+    }
+}
@@ -0,0 +1,16 @@
+use anyhow::Result;
+
+use crate::error::Error;
+use crate::solution::Answer;
+use super::utils::{traverse, Input};
+
+
+pub fn solve(input: &Input) -> Result<Answer> {
+    let (grid, coord, dir) = input;
+
+    let visited = traverse(grid, *coord, dir.clone())
+        .left()
+        .ok_or_else(|| Error::NoSolution(String::from("The guard walks in a loop and never leaves the map")))?;
+
+    Ok(visited.len().into())
+}
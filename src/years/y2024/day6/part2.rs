@@ -0,0 +1,30 @@
+use anyhow::Result;
+
+use crate::error::Error;
+use crate::solution::Answer;
+use super::utils::{traverse, Input};
+
+pub fn solve(input: &Input) -> Result<Answer> {
+    let (grid, coord, dir) = input;
+
+    let mut visited = traverse(grid, *coord, dir.clone())
+        .left()
+        .ok_or_else(|| Error::NoSolution(String::from("The guard walks in a loop and never leaves the map")))?;
+    let mut num_obstacles = 0;
+
+    visited.remove(coord);
+
+    for node in visited.iter() {
+        let mut grid = grid.clone();
+        grid[node.x as usize][node.y as usize] = '#';
+        let new_visited = traverse(&grid, *coord, dir.clone());
+        if new_visited.is_left() {
+            continue
+        } else {
+            num_obstacles += 1;
+        }
+        
+    }
+    
+    Ok(num_obstacles.into())
+}
@@ -1,8 +1,8 @@
-use std::io;
-use std::fs;
 use std::collections::{HashSet, HashMap};
 use either::{Either, Left, Right};
 
+use crate::profile;
+
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
 pub struct Coord {
     pub x: i32,
@@ -48,8 +48,9 @@ impl Direction {
     }
 }
 
-pub fn read_input() -> io::Result<(Vec<Vec<char>>, Coord, Direction)> {
-    let input = fs::read_to_string("inputs/day6/input.txt")?;
+pub type Input = (Vec<Vec<char>>, Coord, Direction);
+
+pub fn parse_input(input: &str) -> Input {
     let mut input_vec = Vec::new();
     let mut start: Coord = Coord::new(0, 0);
     let direction = Direction::North;
@@ -65,17 +66,19 @@ pub fn read_input() -> io::Result<(Vec<Vec<char>>, Coord, Direction)> {
         input_vec.push(line_vec);
     }
 
-    return Ok((input_vec, start, direction));
+    (input_vec, start, direction)
 }
 
-pub fn traverse(grid: &Vec<Vec<char>>, mut coord: Coord, mut dir: Direction) -> Either<HashSet<Coord>, bool> {
+pub fn traverse(grid: &[Vec<char>], mut coord: Coord, mut dir: Direction) -> Either<HashSet<Coord>, bool> {
+    let _span = profile::span("traverse");
     let mut visited = HashMap::new();
     
     *visited.entry(coord).or_insert(0) += 1;
 
+    let (rows, columns) = (grid.len() as i32, grid.first().map_or(0, Vec::len) as i32);
     loop {
         let new_coord = coord.step(&dir);
-        if new_coord.x < 0 || new_coord.x >= grid.len() as i32 || new_coord.y < 0 || new_coord.y >= grid.len() as i32 {
+        if new_coord.x < 0 || new_coord.x >= rows || new_coord.y < 0 || new_coord.y >= columns {
             break;
         }
         if grid[new_coord.x as usize][new_coord.y as usize] == '#' {
@@ -91,5 +94,5 @@ pub fn traverse(grid: &Vec<Vec<char>>, mut coord: Coord, mut dir: Direction) ->
         }
     }
 
-    return Left(visited.keys().cloned().collect());
+    Left(visited.keys().cloned().collect())
 }
\ No newline at end of file
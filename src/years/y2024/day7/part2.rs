@@ -0,0 +1,10 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::utils::evaluate;
+
+pub fn solve(inputs: &[(i64, Vec<i64>)]) -> Result<Answer> {
+    let result = evaluate(inputs, true);
+
+    Ok(result.into())
+}
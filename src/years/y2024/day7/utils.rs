@@ -0,0 +1,61 @@
+use crate::error::{parse_at, Error};
+
+pub fn parse_input(input: &str) -> Result<Vec<(i64, Vec<i64>)>, Error> {
+    let mut input_structured = Vec::new();
+
+    for line in input.lines() {
+        let Some((result, operands)) = line.split_once(": ") else {
+            return Err(Error::missing(input, line, "Expected an equation such as 190: 10 19"));
+        };
+        let result = parse_at(input, result, "a test value")?;
+        let operands = operands
+            .split_whitespace()
+            .map(|o| parse_at(input, o, "a number"))
+            .collect::<Result<_, _>>()?;
+        input_structured.push((result, operands));
+    }
+
+    Ok(input_structured)
+}
+
+fn find_recursive(result: i64, operands: &[i64], current: i64, index: usize, is_part2: bool) -> bool {
+    if index == operands.len() {
+        return current == result;
+    }
+
+    if find_recursive(result, operands, current + operands[index], index + 1, is_part2) {
+        return true;
+    }
+    
+    if find_recursive(result, operands, current * operands[index], index + 1, is_part2) {
+        return true;
+    }
+
+    if is_part2 {
+        let concatenated = format!("{}{}", current, operands[index]);
+        let concatenated_int = concatenated.parse::<i64>().unwrap();
+        if find_recursive(result, operands, concatenated_int, index + 1, is_part2) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn find_expression(result: i64, operands: &[i64], is_part2: bool) -> bool {
+    if find_recursive(result, operands, operands[0], 1, is_part2) {
+        return true;
+    }
+
+    false
+}
+
+pub fn evaluate(inputs: &[(i64, Vec<i64>)], is_part2: bool) -> i64{
+    let mut sum = 0;
+    for input in inputs{
+        if find_expression(input.0, &input.1, is_part2) {
+            sum += input.0;
+        }
+    }
+    sum
+}
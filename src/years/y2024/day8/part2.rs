@@ -0,0 +1,23 @@
+use std::collections::HashSet;
+
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::utils::{find_anti_nodes, Coord, Input};
+
+pub fn solve(input: &Input) -> Result<Answer> {
+    let (map, bounds) = input;
+
+    let mut anti_nodes: HashSet<Coord> = HashSet::new();
+
+    for antennas in map.values() {
+        for (i, coord1) in antennas.iter().enumerate() {
+            for coord2 in antennas.iter().skip(i + 1) {
+                let nodes = find_anti_nodes(coord1, coord2, *bounds, true);
+                anti_nodes.extend(nodes);
+            }
+        }
+    }
+
+    Ok(anti_nodes.len().into())
+}
@@ -1,5 +1,3 @@
-use std::fs;
-use std::io;
 use std::collections::HashMap;
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
@@ -8,11 +6,12 @@ pub struct Coord {
     pub y: i32,
 }
 
-pub fn read_input() -> io::Result<(HashMap<char, Vec<Coord>>, (i32, i32))> {
-    let input = fs::read_to_string("inputs/day8/input.txt")?;
+pub type Input = (HashMap<char, Vec<Coord>>, (i32, i32));
+
+pub fn parse_input(input: &str) -> Input {
     let lines: Vec<&str> = input.lines().collect();
 
-    let num_chars_first_line = lines.get(0).map_or(0, |line| line.chars().count());
+    let num_chars_first_line = lines.first().map_or(0, |line| line.chars().count());
 
     let mut map = HashMap::new();
 
@@ -25,7 +24,7 @@ pub fn read_input() -> io::Result<(HashMap<char, Vec<Coord>>, (i32, i32))> {
             map.entry(c).or_insert_with(Vec::new).push(coord);
         }
     }
-    return Ok((map, (lines.len() as i32, num_chars_first_line as i32)));
+    (map, (lines.len() as i32, num_chars_first_line as i32))
 }
 
 
@@ -64,5 +63,5 @@ pub fn find_anti_nodes(coord1: &Coord, coord2: &Coord, bounds: (i32, i32), is_pa
         }
     }
 
-    return antinodes; 
+    antinodes
 }
\ No newline at end of file
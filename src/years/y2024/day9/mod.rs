@@ -0,0 +1,38 @@
+pub mod utils;
+pub mod part1;
+pub mod part2;
+
+use anyhow::Result;
+
+use crate::error::Error;
+use crate::shape;
+use crate::solution::{Answer, Params, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<String>;
+
+    const YEAR: u16 = 2024;
+
+    const DAY: u8 = 9;
+
+    fn validate(&self, input: &str) -> Result<()> {
+        if input.is_empty() {
+            return Err(Error::missing(input, input, "Expected a disk map of digits").into());
+        }
+        Ok(shape::only(input, input, "0123456789")?)
+    }
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(utils::parse_input(input)?)
+    }
+
+    fn part1(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part1::solve(input)
+    }
+
+    fn part2(&self, input: &Self::Input, _params: &Params) -> Result<Answer> {
+        part2::solve(input)
+    }
+}
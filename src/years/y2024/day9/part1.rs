@@ -0,0 +1,14 @@
+use anyhow::Result;
+
+use crate::solution::Answer;
+use super::utils::{checksum, pack};
+
+pub fn solve(input: &[String]) -> Result<Answer> {
+    let mut input = input.to_vec();
+
+    pack(&mut input);
+
+    let sum = checksum(&input);
+
+    Ok(sum.into())
+}
@@ -1,14 +1,14 @@
-use std::io;
-use std::fs;
-
-pub fn read_input() -> io::Result<Vec<String>> {
-    let input = fs::read_to_string("inputs/day9/input.txt")?;
+use crate::error::Error;
 
+pub fn parse_input(input: &str) -> Result<Vec<String>, Error> {
     let mut is_file = true;
     let mut id: u32 = 0;
     let mut result = Vec::new();
-    for c in input.chars() {
-        let c_int = c.to_digit(10).unwrap();
+    let disk_map = input.trim_end();
+    for (i, c) in disk_map.char_indices() {
+        let c_int = c
+            .to_digit(10)
+            .ok_or_else(|| Error::parse(input, &disk_map[i..i + c.len_utf8()], "Expected a digit"))?;
 
         if is_file {
             for _ in 0..c_int {
@@ -24,12 +24,12 @@ pub fn read_input() -> io::Result<Vec<String>> {
         }
     }
 
-    return Ok(result);
+    Ok(result)
 }
 
-pub fn pack(input: &mut Vec<String>) {
+pub fn pack(input: &mut [String]) {
     let mut left = 0;
-    let mut right = input.len() - 1;
+    let mut right = input.len().saturating_sub(1);
 
     while left < right {
         while left < right && input[left] != "." {
@@ -50,10 +50,10 @@ pub fn pack(input: &mut Vec<String>) {
     }
 }
 
-pub fn pack2(input: &mut Vec<String>) {
+pub fn pack2(input: &mut [String]) {
     let len = input.len();
     let mut left = 0;
-    let mut right = len - 1;
+    let mut right = len.saturating_sub(1);
 
     while right > left {
         while left < len && input[left] != "." {
@@ -114,7 +114,7 @@ pub fn pack2(input: &mut Vec<String>) {
 }
 
 
-pub fn checksum(input: Vec<String>) -> i64 {
+pub fn checksum(input: &[String]) -> i64 {
     input
         .iter()
         .enumerate()
@@ -123,6 +123,6 @@ pub fn checksum(input: Vec<String>) -> i64 {
                 return None;
             }
             let value = s.parse::<i64>().unwrap();
-            return Some(i as i64 * value);
+            Some(i as i64 * value)
     }).sum()
 }
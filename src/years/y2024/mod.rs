@@ -0,0 +1,22 @@
+days! {
+    day1 => Day1,
+    day2 => Day2,
+    day3 => Day3,
+    day4 => Day4,
+    day5 => Day5,
+    day6 => Day6,
+    day7 => Day7,
+    day8 => Day8,
+    day9 => Day9,
+    day10 => Day10,
+    day11 => Day11,
+    day12 => Day12,
+    day13 => Day13,
+    day14 => Day14,
+    day15 => Day15,
+    day16 => Day16,
+    day17 => Day17,
+    day18 => Day18,
+    day19 => Day19,
+    day20 => Day20,
+}